@@ -29,18 +29,30 @@
 
 
 extern crate num_traits as num;
-#[macro_use]
+#[cfg_attr(test, macro_use)]
 #[cfg(feature = "approx")]
 extern crate approx;
 #[cfg(feature = "serde")]
 #[macro_use]
 extern crate serde;
+#[cfg(feature = "euclid")]
+extern crate euclid;
+#[cfg(feature = "nalgebra")]
+extern crate nalgebra;
+#[cfg(feature = "proptest")]
+#[cfg_attr(test, macro_use)]
+extern crate proptest;
 
 use std::ops::*;
-use std::f64::consts;
+use std::f64::consts::PI;
 use std::fmt;
 use std::convert::From;
-use num::{Float, NumCast};
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::hash::{Hash, Hasher};
+use std::error::Error;
+use std::num::ParseFloatError;
+use num::{Float, NumCast, One, Zero};
 
 /// An angular quantity measured in degrees.
 ///
@@ -82,6 +94,90 @@ pub struct ArcMinutes<T>(pub T);
 #[repr(transparent)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 pub struct ArcSeconds<T>(pub T);
+/// An angular quantity measured in revolutions.
+///
+/// Revolutions are equivalent to `Turns`, but named for rotational
+/// machinery. They are uniquely defined from 0..1.
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd, Hash)]
+#[repr(transparent)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Revolutions<T>(pub T);
+
+/// Commonly used angles.
+pub mod consts {
+    use super::{Deg, Rad, Turns};
+    use std::f64::consts::{PI, FRAC_PI_2, FRAC_PI_3, FRAC_PI_4, FRAC_PI_6};
+
+    /// 30 degrees.
+    pub const DEG_30: Deg<f64> = Deg(30.0);
+    /// 45 degrees.
+    pub const DEG_45: Deg<f64> = Deg(45.0);
+    /// 60 degrees.
+    pub const DEG_60: Deg<f64> = Deg(60.0);
+    /// 90 degrees.
+    pub const DEG_90: Deg<f64> = Deg(90.0);
+    /// 180 degrees.
+    pub const DEG_180: Deg<f64> = Deg(180.0);
+    /// 270 degrees.
+    pub const DEG_270: Deg<f64> = Deg(270.0);
+
+    /// π radians.
+    pub const RAD_PI: Rad<f64> = Rad(PI);
+    /// π/2 radians.
+    pub const RAD_FRAC_PI_2: Rad<f64> = Rad(FRAC_PI_2);
+    /// π/3 radians.
+    pub const RAD_FRAC_PI_3: Rad<f64> = Rad(FRAC_PI_3);
+    /// π/4 radians.
+    pub const RAD_FRAC_PI_4: Rad<f64> = Rad(FRAC_PI_4);
+    /// π/6 radians.
+    pub const RAD_FRAC_PI_6: Rad<f64> = Rad(FRAC_PI_6);
+
+    /// A quarter turn.
+    pub const TURNS_QUARTER: Turns<f64> = Turns(0.25);
+    /// A half turn.
+    pub const TURNS_HALF: Turns<f64> = Turns(0.5);
+    /// A full turn.
+    pub const TURNS_FULL: Turns<f64> = Turns(1.0);
+}
+/// Property-based testing strategies producing arbitrary angles.
+///
+/// Each strategy mixes boundary values (zero, a half turn, exactly a full turn and
+/// its negative) with values spread over several turns either side of zero and
+/// occasional large magnitudes.
+#[cfg(feature = "proptest")]
+pub mod strategy {
+    use super::{ArcMinutes, ArcSeconds, Deg, Gon, Rad, Revolutions, Turns};
+    use std::f64::consts::PI;
+    use proptest::prelude::*;
+
+    fn any_scalar(period: f64) -> impl Strategy<Value = f64> {
+        prop_oneof![
+            Just(0.0),
+            Just(period / 2.0),
+            Just(period),
+            Just(-period),
+            -period * 4.0..period * 4.0,
+            -period * 1e6..period * 1e6,
+        ]
+    }
+
+    macro_rules! impl_any_angle {
+        ($name: ident, $Struct: ident, $period: expr) => {
+            /// Generate an arbitrary angle, including boundary values.
+            pub fn $name() -> impl Strategy<Value = $Struct<f64>> {
+                any_scalar($period).prop_map($Struct)
+            }
+        }
+    }
+
+    impl_any_angle!(any_deg, Deg, 360.0);
+    impl_any_angle!(any_gon, Gon, 400.0);
+    impl_any_angle!(any_rad, Rad, PI * 2.0);
+    impl_any_angle!(any_turns, Turns, 1.0);
+    impl_any_angle!(any_arc_minutes, ArcMinutes, 360.0 * 60.0);
+    impl_any_angle!(any_arc_seconds, ArcSeconds, 360.0 * 3600.0);
+    impl_any_angle!(any_revolutions, Revolutions, 1.0);
+}
 
 /// Construct `Self` from an angle.
 ///
@@ -95,6 +191,33 @@ pub trait FromAngle<T>
     fn from_angle(from: T) -> Self;
 }
 
+/// Fallibly construct an angle by converting from another type.
+///
+/// Provided automatically for every `FromAngle` conversion. The conversion fails with
+/// `AngleError::NonFinite` if the result is infinite or NaN. The standard `TryFrom`
+/// trait is already implemented infallibly through the `From` impls, so it cannot
+/// report this error.
+pub trait TryFromAngle<T>: Sized
+    where T: Angle
+{
+    /// Construct `Self` by converting a `T`, rejecting non-finite results.
+    fn try_from_angle(from: T) -> Result<Self, AngleError>;
+}
+
+impl<T, U> TryFromAngle<T> for U
+    where T: Angle,
+          U: Angle + FromAngle<T>,
+{
+    fn try_from_angle(from: T) -> Result<U, AngleError> {
+        let converted = U::from_angle(from);
+        if converted.scalar().is_finite() {
+            Ok(converted)
+        } else {
+            Err(AngleError::NonFinite)
+        }
+    }
+}
+
 /// Construct an angle by converting from another type.
 ///
 /// IntoAngle is provided automatically based on FromAngle.
@@ -174,667 +297,4688 @@ pub trait Angle: Clone + FromAngle<Self> + PartialEq + PartialOrd + num::Zero {
     ///
     /// Equivalent to `full_turn() - self`.
     fn reflect_x(self) -> Self;
-}
 
-/// A trait for linear interpolation between angles.
-pub trait Interpolate: Angle {
-    /// Perform a linear interpolation between two angles.
+    /// Compose the rotation with itself `n` times.
     ///
-    /// This method will always follow the shortest past between
-    /// the two angles. This means it will go backward if the
-    /// angles are more than a half turn apart. To force the interpolation
-    /// to go forward, use `interpolate_forward`.
-    /// The output is not normalized, and may exceed a
-    /// full turn if it interpolates backward,
-    /// even if both inputs are normalized.
-    /// The angles may be represented in different units.
-    fn interpolate<U>(&self, right: &U, pos: Self::Scalar) -> Self
-        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>;
+    /// Equivalent to multiplying the angle by `n`. A negative `n` rotates
+    /// the other way. The output is not normalized.
+    fn times(self, n: i32) -> Self {
+        Self::new(self.scalar() * cast(n).unwrap())
+    }
 
-    /// Perform a linear interpolation between two angles,
-    /// going forward from `self` to `right`.
+    /// Whether two angles are equal to within `epsilon`.
     ///
-    /// Unlike `interpolate` this will always go forward from `self` to `right`,
-    /// even if going backward would take a shorter path. The output is not
-    /// normalized, but should remain normalized if both `self` and `right` are.
-    /// The angles may be represented in different units.
-    fn interpolate_forward<U>(&self, right: &U, pos: Self::Scalar) -> Self
-        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>;
-}
-
-macro_rules! impl_angle {
-    ($Struct: ident, $period: expr) => {
-        impl<T: Float> Angle for $Struct<T>
-        {
-            type Scalar = T;
+    /// `other` is converted into the units of `self` and the raw scalars are compared,
+    /// so no wrapping is performed. Unlike the `approx` trait implementations, this
+    /// is available regardless of enabled features.
+    fn close_to<U>(&self, other: &U, epsilon: Self::Scalar) -> bool
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        (self.scalar() - other.clone().into_angle().scalar()).abs() <= epsilon
+    }
 
-            fn new(value: T) -> $Struct<T> {
-                $Struct(value)
-            }
+    /// Return the negation of an angle, normalized into the standard domain.
+    ///
+    /// Unlike the `Neg` operator, the result is always in `[0, period())`.
+    fn negate_normalized(&self) -> Self {
+        Self::new(-self.scalar()).normalize()
+    }
 
-            fn period() -> T {
-                cast($period).unwrap()
-            }
+    /// Wrap the angle into `[0, period())`.
+    ///
+    /// This is the "compass heading" convention, eg. `[0, 360)` for degrees.
+    /// Equivalent to `normalize()`.
+    fn wrapped_unsigned(&self) -> Self {
+        self.clone().normalize()
+    }
+    /// Wrap the angle into `[-period() / 2, period() / 2)`.
+    ///
+    /// This is the "relative bearing" convention, eg. `[-180, 180)` for degrees,
+    /// where negative values are clockwise of zero.
+    fn wrapped_signed(&self) -> Self {
+        let normalized = self.clone().normalize();
+        if normalized.scalar() >= Self::half_turn().scalar() {
+            Self::new(normalized.scalar() - Self::period())
+        } else {
+            normalized
+        }
+    }
 
-            fn scalar(&self) -> T {
-                self.0
-            }
-            fn set_scalar(&mut self, value: T) {
-                self.0 = value;
-            }
-            fn is_normalized(&self) -> bool {
-                self.0 >= T::zero() && self.0 < Self::period()
-            }
+    /// Add two angles, returning `None` if either operand or the result is not finite.
+    fn checked_add<U>(self, rhs: U) -> Option<Self>
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let rhs = rhs.into_angle();
+        let result = self.scalar() + rhs.scalar();
+        if self.scalar().is_finite() && rhs.scalar().is_finite() && result.is_finite() {
+            Some(Self::new(result))
+        } else {
+            None
+        }
+    }
+    /// Subtract two angles, returning `None` if either operand or the result is not finite.
+    fn checked_sub<U>(self, rhs: U) -> Option<Self>
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let rhs = rhs.into_angle();
+        let result = self.scalar() - rhs.scalar();
+        if self.scalar().is_finite() && rhs.scalar().is_finite() && result.is_finite() {
+            Some(Self::new(result))
+        } else {
+            None
+        }
+    }
+    /// Divide the angle by a scalar, returning `None` if `divisor` is zero or the
+    /// result is not finite.
+    fn checked_div(self, divisor: Self::Scalar) -> Option<Self> {
+        let result = self.scalar() / divisor;
+        if divisor != Self::Scalar::zero() && result.is_finite() {
+            Some(Self::new(result))
+        } else {
+            None
+        }
+    }
 
-            fn normalize(self) -> $Struct<T> {
-                if !self.is_normalized() {
-                    let shifted = self.0 % Self::period();
-                    if shifted < T::zero() {
-                        $Struct(shifted + Self::period())
-                    } else {
-                        $Struct(shifted)
-                    }
-                } else {
-                    self
-                }
-            }
+    /// Compare two angles after normalizing both into the standard domain.
+    ///
+    /// Returns `None` only if either value is NaN.
+    fn partial_cmp_normalized<U>(&self, other: &U) -> Option<Ordering>
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let other = other.clone().into_angle().normalize();
+        self.clone().normalize().scalar().partial_cmp(&other.scalar())
+    }
 
-            fn sin(self) -> T {
-                Rad::from_angle(self).0.sin()
-            }
-            fn cos(self) -> T {
-                Rad::from_angle(self).0.cos()
-            }
-            fn tan(self) -> T {
-                Rad::from_angle(self).0.tan()
-            }
-            fn sin_cos(self) -> (T, T) {
-                Rad::from_angle(self).0.sin_cos()
-            }
-            fn asin(value: T) -> $Struct<T> {
-                $Struct::from_angle(Rad(value.asin()))
-            }
-            fn acos(value: T) -> $Struct<T> {
-                $Struct::from_angle(Rad(value.acos()))
-            }
-            fn atan(value: T) -> $Struct<T> {
-                $Struct::from_angle(Rad(value.atan()))
-            }
-            fn atan2(y: T, x: T) -> $Struct<T> {
-                $Struct::from_angle(Rad(y.atan2(x)))
-            }
+    /// Construct an angle from a scalar value in radians.
+    fn from_radians(radians: Self::Scalar) -> Self {
+        Self::new(convert_scalar(radians, Rad::<Self::Scalar>::period(), Self::period()))
+    }
+    /// Construct an angle from a scalar value in degrees.
+    fn from_degrees(degrees: Self::Scalar) -> Self {
+        Self::new(convert_scalar(degrees, Deg::<Self::Scalar>::period(), Self::period()))
+    }
 
-            fn full_turn() -> Self {
-                $Struct(Self::period())
-            }
-            fn half_turn() -> Self {
-                $Struct(cast::<_, Self::Scalar>(0.5).unwrap() * Self::period())
-            }
-            fn quarter_turn() -> Self {
-                $Struct(cast::<_, Self::Scalar>(0.25).unwrap() * Self::period())
-            }
-            fn invert(self) -> Self {
-                self + Self::half_turn()
-            }
-            fn reflect_x(self) -> Self {
-                Self::full_turn() - self
-            }
+    /// Quantize the angle to an integer fraction of a full turn with `bits` bits of precision.
+    ///
+    /// The angle is normalized and scaled so that a full turn maps to `2^bits`,
+    /// then rounded. Values that round up to a full turn wrap to zero.
+    ///
+    /// Returns `None` if the angle is not finite, or if `bits` is more than 64, since
+    /// the result would not fit in a `u64`.
+    fn to_fixed_bits(&self, bits: u32) -> Option<u64> {
+        if bits > 64 || !self.scalar().is_finite() {
+            return None;
+        }
+        let scale: Self::Scalar = cast(2f64.powi(bits as i32)).unwrap();
+        let quantized = (self.clone().normalize().scalar() / Self::period() * scale).round();
+        if quantized >= scale {
+            Some(0)
+        } else {
+            cast(quantized)
         }
+    }
+    /// Construct an angle from an integer fraction of a full turn with `bits` bits of precision.
+    ///
+    /// The inverse of `to_fixed_bits`. Values of `2^bits` or more produce angles
+    /// of a full turn or more.
+    fn from_fixed_bits(value: u64, bits: u32) -> Self {
+        let scale: Self::Scalar = cast(2f64.powi(bits as i32)).unwrap();
+        Self::new(cast::<_, Self::Scalar>(value).unwrap() / scale * Self::period())
+    }
 
-        impl<T: Float> Interpolate for $Struct<T> {
-            fn interpolate<U>(&self, right: &U, pos: Self::Scalar) -> Self
-                where U: Clone + IntoAngle<Self, OutputScalar=Self::Scalar>
-            {
-                let end = right.clone().into_angle();
-                let forward_distance = (end.0 - self.0).abs();
-                let inv_pos = cast::<_, Self::Scalar>(1.0).unwrap() - pos;
-                
-                if forward_distance > Self::half_turn().0 {
-                    if *self > end {
-                        $Struct(self.0 * inv_pos + (end.0 + Self::period()) * pos)
-                    } else {
-                        $Struct((self.0 + Self::period()) * inv_pos + end.0 * pos)
-                    }
-                } else {
-                    $Struct(self.0 * inv_pos + end.0 * pos)
-                }
-            }
+    /// Compute the signed shortest difference `self - other`.
+    ///
+    /// Unlike the `Sub` operator, the result is wrapped into
+    /// `[-period() / 2, period() / 2)`, so `Deg(10.0).delta(&Deg(350.0))` is `Deg(20.0)`.
+    fn delta<U>(&self, other: &U) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        Self::new(self.scalar() - other.clone().into_angle().scalar()).wrapped_signed()
+    }
 
-            fn interpolate_forward<U>(&self, right: &U, pos: Self::Scalar) -> Self
-                where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
-            {
-                let inv_pos = cast::<_, Self::Scalar>(1.0).unwrap() - pos;
-                $Struct(self.0 * inv_pos + right.clone().into_angle().0 * pos)
-            }
-        }
+    /// Whether the shortest angular distance between `self` and `center` is at most `tolerance`.
+    fn within<U>(&self, center: U, tolerance: Self) -> bool
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let center: Self = center.into_angle();
+        self.delta(&center).scalar().abs() <= tolerance.scalar()
+    }
 
-        #[cfg(feature = "approx")]
-        impl<T: Float + approx::AbsDiffEq> approx::AbsDiffEq for $Struct<T>
-            where T::Epsilon: Clone,
-        {
-            type Epsilon = T::Epsilon;
+    /// Whether the angle lies on the arc going counterclockwise from `start` to `end`,
+    /// counting both endpoints as on the arc.
+    ///
+    /// If `start` and `end` are the same direction, only that direction is on the arc.
+    fn is_between_inclusive<U, V>(&self, start: U, end: V) -> bool
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>,
+              V: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let start: Self = start.into_angle();
+        let end: Self = end.into_angle();
+        let offset = Self::new(self.scalar() - start.scalar()).normalize().scalar();
+        offset <= Self::new(end.scalar() - start.scalar()).normalize().scalar()
+    }
+    /// Whether the angle lies strictly inside the arc going counterclockwise from
+    /// `start` to `end`, excluding both endpoints.
+    ///
+    /// If `start` and `end` are the same direction, no angle is inside the arc.
+    fn is_between_exclusive<U, V>(&self, start: U, end: V) -> bool
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>,
+              V: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let start: Self = start.into_angle();
+        let end: Self = end.into_angle();
+        let offset = Self::new(self.scalar() - start.scalar()).normalize().scalar();
+        let width = Self::new(end.scalar() - start.scalar()).normalize().scalar();
+        offset > Self::Scalar::zero() && offset < width
+    }
 
-            fn default_epsilon() -> Self::Epsilon {
-                T::default_epsilon()
-            }
+    /// Return a wrapper that displays only the scalar value, without a unit suffix.
+    ///
+    /// Formatting options such as precision are forwarded to the scalar.
+    fn bare(&self) -> Bare<Self> {
+        Bare(self.clone())
+    }
 
-            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool
-            {
-                let inv_self = self.clone().reflect_x();
+    /// Clamp the angle to the arc going counterclockwise from `min` to `max`.
+    ///
+    /// Returns the clamped angle and whether clamping occurred. Angles inside the
+    /// arc are returned unchanged. Angles outside of it are moved to the nearest
+    /// endpoint, which is returned normalized. The arc may wrap through zero.
+    fn clamp_to_fov<U, V>(self, min: U, max: V) -> (Self, bool)
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>,
+              V: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let min: Self = min.into_angle();
+        let max: Self = max.into_angle();
+        let width = Self::new(max.scalar() - min.scalar()).normalize();
+        let offset = Self::new(self.scalar() - min.scalar()).normalize();
+        if offset.scalar() <= width.scalar() {
+            return (self, false);
+        }
 
-                self.0.abs_diff_eq(&other.0, epsilon.clone())
-                || self.0.abs_diff_eq(&other.clone().reflect_x().0,
-                      epsilon.clone())
-                || inv_self.0.abs_diff_eq(&other.0, epsilon)
-            }
+        if self.delta(&min).scalar().abs() <= self.delta(&max).scalar().abs() {
+            (min.normalize(), true)
+        } else {
+            (max.normalize(), true)
         }
+    }
 
-        #[cfg(feature = "approx")]
-        impl<T: Float + approx::RelativeEq> approx::RelativeEq for $Struct<T>
-            where T::Epsilon: Clone,
-        {
-            fn default_max_relative() -> Self::Epsilon {
-                T::default_max_relative()
-            }
+    /// Reduce the angle under `n`-fold rotational symmetry.
+    ///
+    /// Returns the equivalent angle in `[0, period() / n)`. For example, a hex nut
+    /// has 6-fold symmetry, so `Deg(130.0).fold_symmetry(6)` is `Deg(10.0)`.
+    /// An `n` of zero is treated as no symmetry, and is equivalent to `normalize()`.
+    fn fold_symmetry(&self, n: u32) -> Self {
+        let normalized = self.clone().normalize();
+        if n <= 1 {
+            return normalized;
+        }
+        let sector = Self::period() / cast(n).unwrap();
+        Self::new(normalized.scalar() % sector)
+    }
 
-            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, 
-                           max_relative: Self::Epsilon) -> bool {
-                let inv_self = self.clone().reflect_x();
+    /// Return the abbreviation of the nearest compass point, eg. `"NNE"`.
+    ///
+    /// The angle is treated as a compass bearing, measured clockwise from north.
+    /// Returns `None` if the angle is not finite.
+    fn to_compass_abbr(&self, points: CompassResolution) -> Option<&'static str> {
+        let count = points.count();
+        let stride = COMPASS_POINTS.len() / count;
+        compass_sector(self, count).map(|index| COMPASS_POINTS[index * stride])
+    }
+    /// Parse a compass point abbreviation, eg. `"NNE"`, into the bearing at its center.
+    ///
+    /// Parsing is case-insensitive. Any of the 16 standard points are accepted.
+    fn from_compass_abbr(s: &str) -> Result<Self, ParseAngleError> {
+        let s = s.trim();
+        COMPASS_POINTS.iter()
+            .position(|point| point.eq_ignore_ascii_case(s))
+            .map(|index| {
+                let sector = Self::period() / cast(COMPASS_POINTS.len()).unwrap();
+                Self::new(sector * cast(index).unwrap())
+            })
+            .ok_or(ParseAngleError::InvalidCompassPoint)
+    }
 
-                self.0.relative_eq(&other.0, epsilon.clone(), max_relative.clone())
-                || self.0.relative_eq(&other.clone().reflect_x().0, 
-                      epsilon.clone(), max_relative.clone())
-                || inv_self.0.relative_eq(&other.0, epsilon, max_relative)
-            }
-        }
+    /// Return the coterminal value of `self` within half a turn of `reference`.
+    ///
+    /// The result is not normalized, eg. `Deg(10.0).wrap_near(Deg(350.0))` is `Deg(370.0)`.
+    /// Rather than the principal value, this picks the branch (number of turns) closest
+    /// to `reference`, which keeps related signals continuous when plotted together:
+    /// `Deg(10.0).wrap_near(Deg(720.0))` is `Deg(730.0)`.
+    ///
+    /// Equivalently, this normalizes `self` into `[reference - period() / 2,
+    /// reference + period() / 2)`; with a zero reference it matches `wrapped_signed`.
+    fn wrap_near<U>(&self, reference: U) -> Self
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let reference: Self = reference.into_angle();
+        Self::new(reference.scalar() + self.delta(&reference).scalar())
+    }
 
-        #[cfg(feature = "approx")]
-        impl<T: Float + approx::UlpsEq> approx::UlpsEq for $Struct<T>
-        where T::Epsilon: Clone,
-        {
-            fn default_max_ulps() -> u32 {
-                T::default_max_ulps()
-            }
-            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
-                let inv_self = self.clone().reflect_x();
+    /// Return the coterminal representation of `self` nearest to `reference`.
+    ///
+    /// Equivalent to `wrap_near`, eg. `Deg(10.0).align_to(Deg(720.0))` is `Deg(730.0)`.
+    fn align_to<U>(&self, reference: U) -> Self
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        self.wrap_near(reference)
+    }
 
-                self.0.ulps_eq(&other.0, epsilon.clone(), max_ulps)
-                || self.0.ulps_eq(&other.clone().reflect_x().0, epsilon.clone(), max_ulps)
-                || inv_self.0.ulps_eq(&other.0, epsilon, max_ulps)
-            }
-        }
+    /// Return the smaller of two angles, ignoring NaN.
+    ///
+    /// Compares the raw scalars, like `f64::min`. If one value is NaN, the other is returned.
+    fn min_ignore_nan(self, other: Self) -> Self {
+        Self::new(self.scalar().min(other.scalar()))
+    }
+    /// Return the larger of two angles, ignoring NaN.
+    ///
+    /// Compares the raw scalars, like `f64::max`. If one value is NaN, the other is returned.
+    fn max_ignore_nan(self, other: Self) -> Self {
+        Self::new(self.scalar().max(other.scalar()))
+    }
 
-        impl<T: Rem<T, Output=T>> Rem for $Struct<T> {
-            type Output=$Struct<T>;
-            fn rem(self, rhs: $Struct<T>) -> $Struct<T> {
-                $Struct(self.0 % rhs.0)
-            }
-        }
+    /// Compute the absolute shortest difference between two angles.
+    ///
+    /// The magnitude of `delta`, always in `[0, period() / 2]`.
+    fn abs_diff<U>(&self, other: &U) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        Self::new(self.delta(other).scalar().abs())
+    }
 
-        impl<T: RemAssign> RemAssign for $Struct<T> {
-            fn rem_assign(&mut self, rhs: $Struct<T>) {
-                self.0 %= rhs.0;
-            }
+    /// Determine the direction of the shortest turn from `self` to `other`.
+    ///
+    /// Returns `Ordering::Greater` for a counterclockwise turn, `Ordering::Less` for a
+    /// clockwise turn, and `Ordering::Equal` if the angles are parallel or exactly opposite.
+    fn turn_direction<U>(&self, other: &U) -> Ordering
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let other = other.clone().into_angle();
+        let turn = Self::new(other.scalar() - self.scalar()).wrapped_signed().scalar();
+        if turn == Self::Scalar::zero() || turn.abs() == Self::half_turn().scalar() {
+            Ordering::Equal
+        } else if turn > Self::Scalar::zero() {
+            Ordering::Greater
+        } else {
+            Ordering::Less
         }
+    }
 
-        impl<U, T> Add<U> for $Struct<T> 
-            where T: Float + Add<T, Output=T>,
-                  U: IntoAngle<$Struct<T>, OutputScalar=T>
-        {
-            type Output=$Struct<T>;
-            fn add(self, rhs: U) -> $Struct<T> {
-                $Struct(self.0 + rhs.into_angle().0)
-            }
-        }
+    /// Reconstruct a normalized angle from its sine and cosine.
+    ///
+    /// Uses `atan2`, so the quadrant is recovered from the signs of both values.
+    fn from_sin_cos(sin: Self::Scalar, cos: Self::Scalar) -> Self {
+        Self::atan2(sin, cos).normalize()
+    }
+    /// Compute the normalized direction of an `(x, y)` point from the origin.
+    ///
+    /// Uses `atan2(y, x)`; the origin itself produces a zero angle.
+    fn from_point(point: (Self::Scalar, Self::Scalar)) -> Self {
+        Self::atan2(point.1, point.0).normalize()
+    }
 
-        impl<U, T> AddAssign<U> for $Struct<T> 
-            where T: Float + AddAssign<T>,
-                  U: IntoAngle<$Struct<T>, OutputScalar=T>
-        {
-            fn add_assign(&mut self, rhs: U) {
-                self.0 += rhs.into_angle().0;
-            }
+    /// Convert the angle into another unit and normalize it.
+    ///
+    /// Equivalent to `U::from_angle(self).normalize()`.
+    fn normalize_into<U>(self) -> U
+        where U: Angle + FromAngle<Self>
+    {
+        U::from_angle(self).normalize()
+    }
+
+    /// Scale the angle's distance from `pivot` by `factor`.
+    ///
+    /// Computes `pivot + self.delta(pivot) * factor`, using the signed shortest
+    /// difference so the scaling never goes the long way around. The result is not normalized.
+    fn scale_about<U>(&self, pivot: U, factor: Self::Scalar) -> Self
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let pivot: Self = pivot.into_angle();
+        Self::new(pivot.scalar() + self.delta(&pivot).scalar() * factor)
+    }
+
+    /// Whether the angle is within `tol` of a cardinal direction.
+    ///
+    /// The cardinal directions are the multiples of a quarter turn.
+    fn is_cardinal(&self, tol: Self::Scalar) -> bool {
+        let quarter = Self::quarter_turn().scalar();
+        let offset = self.clone().normalize().scalar() % quarter;
+        offset.min(quarter - offset) <= tol
+    }
+    /// Whether the angle is within `tol` of an intercardinal direction.
+    ///
+    /// The intercardinal directions lie halfway between the cardinal directions.
+    fn is_intercardinal(&self, tol: Self::Scalar) -> bool {
+        let eighth = Self::quarter_turn().scalar() / cast(2.0).unwrap();
+        Self::new(self.scalar() - eighth).is_cardinal(tol)
+    }
+
+    /// Multiply the angle by `factor` and normalize the result.
+    fn wrapping_scale(self, factor: Self::Scalar) -> Self {
+        Self::new(self.scalar() * factor).normalize()
+    }
+
+    /// Express the angle relative to a `base` angle.
+    ///
+    /// Returns `self - base` wrapped into `[-period() / 2, period() / 2)`, so
+    /// `Deg(100.0).relative_to(Deg(110.0))` is `Deg(-10.0)`. Equivalent to `delta`.
+    fn relative_to<U>(&self, base: U) -> Self
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let base: Self = base.into_angle();
+        self.delta(&base)
+    }
+
+    /// Convert an angle relative to a `base` angle back to an absolute angle.
+    ///
+    /// The inverse of `relative_to`. Returns `(self + base).normalize()`.
+    fn absolute_from<U>(&self, base: U) -> Self
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        Self::new(self.scalar() + base.into_angle().scalar()).normalize()
+    }
+
+    /// Compute the unnormalized cardinal sine, `sin(x) / x`, with `x` in radians.
+    ///
+    /// Returns exactly `1` at zero rather than NaN.
+    fn sinc(self) -> Self::Scalar {
+        let x = Rad::from_angle(self).scalar();
+        if x == Self::Scalar::zero() {
+            Self::Scalar::one()
+        } else {
+            x.sin() / x
         }
+    }
 
-        impl<U, T> Sub<U> for $Struct<T> 
-            where T: Float + Sub<T, Output=T>,
-                  U: IntoAngle<$Struct<T>, OutputScalar=T>
-        {
-            type Output=$Struct<T>;
-            fn sub(self, rhs: U) -> $Struct<T> {
-                $Struct(self.0 - rhs.into_angle().0)
-            }
+    /// Step from `self` toward `target` along the shortest arc, moving at most `max_change`.
+    ///
+    /// Intended as a rate limiter for filtering noisy headings: call once per update
+    /// with the previous output. The result is not normalized.
+    fn limit_step<U>(&self, target: U, max_change: Self) -> Self
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let limit = max_change.scalar().abs();
+        let target = target.into_angle();
+        let step = Self::new(target.scalar() - self.scalar()).wrapped_signed().scalar();
+        Self::new(self.scalar() + step.max(-limit).min(limit))
+    }
+
+    /// Convert the angle to NATO mils, where a full turn is `6400` mils.
+    fn to_mil(&self) -> Self::Scalar {
+        let mils_per_turn: Self::Scalar = cast(6400.0).unwrap();
+        self.scalar() / Self::period() * mils_per_turn
+    }
+    /// Construct an angle from a value in NATO mils, where a full turn is `6400` mils.
+    fn from_mil(mils: Self::Scalar) -> Self {
+        let mils_per_turn: Self::Scalar = cast(6400.0).unwrap();
+        Self::new(mils / mils_per_turn * Self::period())
+    }
+
+    /// Whether the angle is within `tol` of zero on the circle.
+    ///
+    /// Unlike `num::Zero::is_zero`, this tolerates rounding error and treats
+    /// values just below a full turn as near zero.
+    fn is_near_zero(&self, tol: Self::Scalar) -> bool {
+        self.wrapped_signed().scalar().abs() <= tol
+    }
+
+    /// Convert the angle into another unit and scalar type, checking for precision loss.
+    ///
+    /// The converted angle is converted back and compared against `self`. If they differ
+    /// by more than `tol`, in the units of `self`, `AngleError::PrecisionLoss` is returned.
+    /// This also happens if the value cannot be represented in the target scalar type at all.
+    fn try_into_angle_lossy<U: Angle>(self, tol: f64) -> Result<U, AngleError> {
+        let period: f64 = cast(Self::period()).unwrap();
+        let target_period: f64 = cast(U::period()).unwrap();
+        let original: f64 = cast(self.scalar()).ok_or(AngleError::PrecisionLoss)?;
+
+        let scalar = cast(original / period * target_period).ok_or(AngleError::PrecisionLoss)?;
+        let converted = U::new(scalar);
+        let round_trip: f64 = cast(converted.scalar()).ok_or(AngleError::PrecisionLoss)?;
+        let round_trip = round_trip / target_period * period;
+        if (round_trip - original).abs() > tol {
+            Err(AngleError::PrecisionLoss)
+        } else {
+            Ok(converted)
         }
+    }
 
-        impl<U, T> SubAssign<U> for $Struct<T> 
-            where T: Float + SubAssign<T>,
-                  U: IntoAngle<$Struct<T>, OutputScalar=T>
-        {
-            fn sub_assign(&mut self, rhs: U) {
-                self.0 -= rhs.into_angle().0;
-            }
+    /// Return `self` and `other`, both expressed in the unit of `self`.
+    fn to_same_unit<U>(&self, other: U) -> (Self, Self)
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        (self.clone(), other.into_angle())
+    }
+
+    /// Express the normalized angle as a percentage of a full turn, in `[0, 100)`.
+    ///
+    /// Angles of a full turn or more are normalized first, so `Deg(450.0)` is `25`.
+    fn to_percent(&self) -> Self::Scalar {
+        let hundred: Self::Scalar = cast(100.0).unwrap();
+        self.clone().normalize().scalar() / Self::period() * hundred
+    }
+    /// Construct an angle from a percentage of a full turn.
+    ///
+    /// The result is not normalized, so `150` percent is one and a half turns.
+    fn from_percent(pct: Self::Scalar) -> Self {
+        let hundred: Self::Scalar = cast(100.0).unwrap();
+        Self::new(pct / hundred * Self::period())
+    }
+
+    /// Compute the reciprocal (back) bearing, the normalized opposite direction.
+    ///
+    /// Like `invert`, but normalized: `Deg(200.0).back_bearing()` is `Deg(20.0)`.
+    fn back_bearing(&self) -> Self {
+        Self::new(self.scalar() + Self::half_turn().scalar()).normalize()
+    }
+
+    /// Snap the normalized angle to the nearest multiple of `step`.
+    ///
+    /// The grid starts at zero. If `step` does not evenly divide a full turn, the last
+    /// grid point before the seam is closer than `step` to the full turn; values nearer
+    /// the full turn than to any grid point snap to zero. Exact ties between two grid
+    /// points snap to the larger one. The result is normalized.
+    ///
+    /// Returns `None` if `step` is zero or not finite, or if the angle is not finite.
+    fn snap_to(&self, step: Self) -> Option<Self> {
+        let step = step.scalar().abs();
+        if step == Self::Scalar::zero() || !step.is_finite() || !self.scalar().is_finite() {
+            return None;
         }
-        
-        impl<T: Mul<T, Output=T>> Mul<T> for $Struct<T> {
-            type Output=$Struct<T>;
-            fn mul(self, rhs: T) -> $Struct<T> {
-                $Struct(self.0 * rhs)
-            }
+        let value = self.clone().normalize().scalar();
+        let snapped = (value / step).round() * step;
+        if Self::period() - value < (value - snapped).abs() {
+            Some(Self::new(Self::Scalar::zero()))
+        } else {
+            Some(Self::new(snapped).normalize())
         }
-
-        impl<T: MulAssign<T>> MulAssign<T> for $Struct<T> {
-            fn mul_assign(&mut self, rhs: T) {
-                self.0 *= rhs;
+    }
+    /// Return the angle in `allowed` with the smallest shortest-path distance to `self`.
+    ///
+    /// Distances account for wrapping, so `Deg(350.0)` is closer to `Deg(0.0)` than to
+    /// `Deg(300.0)`. Ties go to the earliest candidate. The chosen angle is returned as
+    /// given, without normalizing. Returns `None` if `allowed` is empty.
+    fn snap_to_set<I: IntoIterator<Item = Self>>(&self, allowed: I) -> Option<Self> {
+        let mut best: Option<(Self, Self::Scalar)> = None;
+        for candidate in allowed {
+            let distance = self.delta(&candidate).scalar().abs();
+            let is_closer = match best {
+                Some((_, best_distance)) => distance < best_distance,
+                None => true,
+            };
+            if is_closer {
+                best = Some((candidate, distance));
             }
         }
+        best.map(|(angle, _)| angle)
+    }
 
-        impl<T: Div<T, Output=T>> Div<T> for $Struct<T> {
-            type Output=$Struct<T>;
-            fn div(self, rhs: T) -> $Struct<T> {
-                $Struct(self.0 / rhs)
-            }
-        }
+    /// Return the traditional name of the nearest of the 32 nautical compass points,
+    /// eg. `"NE by N"`.
+    ///
+    /// The angle is treated as a compass bearing, measured clockwise from north.
+    /// Returns `None` if the angle is not finite.
+    fn to_compass32_name(&self) -> Option<&'static str> {
+        compass_sector(self, COMPASS32_POINTS.len()).map(|index| COMPASS32_POINTS[index])
+    }
 
-        impl<T: DivAssign<T>> DivAssign<T> for $Struct<T> {
-            fn div_assign(&mut self, rhs: T) {
-                self.0 /= rhs;
-            }
+    /// Convert the angle to degrees.
+    fn as_deg(&self) -> Deg<Self::Scalar> {
+        Deg::from_angle(self.clone())
+    }
+    /// Convert the angle to radians.
+    fn as_rad(&self) -> Rad<Self::Scalar> {
+        Rad::from_angle(self.clone())
+    }
+    /// Convert the angle to turns.
+    fn as_turns(&self) -> Turns<Self::Scalar> {
+        Turns::from_angle(self.clone())
+    }
+
+    /// Normalize the angle, borrowing `self` if it is already normalized.
+    ///
+    /// Avoids a clone in the common case where no work is needed.
+    fn normalized_ref(&self) -> Cow<'_, Self> {
+        if self.is_normalized() {
+            Cow::Borrowed(self)
+        } else {
+            Cow::Owned(self.clone().normalize())
         }
+    }
 
-        impl<T: Neg<Output=T>> Neg for $Struct<T> {
-            type Output=$Struct<T>;
-            fn neg(self) -> $Struct<T> {
-                $Struct(-self.0)
-            }
+    /// Compute the arctangent of `y / x` using the signs of both to determine the
+    /// quadrant, normalizing the result into `[0, period())`.
+    fn atan2_normalized(y: Self::Scalar, x: Self::Scalar) -> Self {
+        Self::atan2(y, x).normalize()
+    }
+
+    /// Return an angle with the magnitude of `self` and the sign of `sign_source`.
+    ///
+    /// Operates on the raw scalars, like `f64::copysign`.
+    fn copysign<U>(self, sign_source: U) -> Self
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let magnitude = self.scalar().abs();
+        if sign_source.into_angle().scalar().is_sign_negative() {
+            Self::new(-magnitude)
+        } else {
+            Self::new(magnitude)
         }
+    }
 
-        impl<T: Float> num::Zero for $Struct<T> {
-            fn zero() -> $Struct<T> {
-                $Struct(T::zero())
-            }
-            fn is_zero(&self) -> bool {
-                self.0 == T::zero()
-            }
+    /// Force the angle into `[0, period())` by clamping rather than wrapping.
+    ///
+    /// Negative values become zero, and values of a full turn or more become
+    /// `period() * (1 - ε)`, where `ε` is the scalar's machine epsilon, which is just
+    /// below a full turn. NaN is returned unchanged.
+    fn clamp_to_domain(self) -> Self {
+        if self.scalar() < Self::Scalar::zero() {
+            Self::new(Self::Scalar::zero())
+        } else if self.scalar() >= Self::period() {
+            Self::new(Self::period() * (Self::Scalar::one() - Self::Scalar::epsilon()))
+        } else {
+            self
         }
+    }
 
-        impl<T: num::Zero> Default for $Struct<T> {
-            fn default() -> $Struct<T> {
-                $Struct(T::zero())
-            }
+    /// Simultaneously compute sine, cosine and tangent.
+    ///
+    /// The angle is converted to radians once, and the tangent is derived as `sin / cos`.
+    fn sin_cos_tan(self) -> (Self::Scalar, Self::Scalar, Self::Scalar) {
+        let (sin, cos) = Rad::from_angle(self).scalar().sin_cos();
+        (sin, cos, sin / cos)
+    }
+
+    /// Express the angle as a slope in percent grade, `100 * tan(self)`.
+    ///
+    /// A vertical slope, where the cosine is within machine epsilon of zero,
+    /// produces an infinite grade with the sign of the sine.
+    fn to_grade_percent(&self) -> Self::Scalar {
+        let hundred: Self::Scalar = cast(100.0).unwrap();
+        let (sin, cos) = self.clone().sin_cos();
+        if cos.abs() <= Self::Scalar::epsilon() {
+            Self::Scalar::infinity().copysign(sin)
+        } else {
+            hundred * sin / cos
         }
+    }
+    /// Construct a slope angle from a percent grade, `atan(pct / 100)`.
+    ///
+    /// The result is in `(-period() / 4, period() / 4)`, or exactly a quarter turn for
+    /// an infinite grade.
+    fn from_grade_percent(pct: Self::Scalar) -> Self {
+        let hundred: Self::Scalar = cast(100.0).unwrap();
+        Self::from_radians((pct / hundred).atan())
+    }
 
-        impl<T, U> FromAngle<U> for $Struct<T>
-            where U: Angle<Scalar=T>,
-                  T: Float,
-        {
-            fn from_angle(from: U) -> $Struct<T> {
-                $Struct(from.scalar() * $Struct::period() / U::period())
-            }
+    /// Normalize the angle, rejecting angles of more than `max_turns` full turns.
+    ///
+    /// Returns `AngleError::TooLarge` if `|self| / period()` exceeds `max_turns`,
+    /// guarding against runaway accumulators whose normalized value would be meaningless.
+    /// Returns `AngleError::NonFinite` if the angle is infinite or NaN.
+    fn normalize_checked_magnitude(&self, max_turns: Self::Scalar) -> Result<Self, AngleError> {
+        if !self.scalar().is_finite() {
+            Err(AngleError::NonFinite)
+        } else if self.scalar().abs() / Self::period() > max_turns {
+            Err(AngleError::TooLarge)
+        } else {
+            Ok(self.clone().normalize())
         }
     }
-}
 
-macro_rules! impl_from_for_angle {
-    ($from: ty, $to: ty) => {
-        impl<T: Float> From<$from> for $to {
-            fn from(from: $from) -> $to {Self::from_angle(from)}
+    /// Format the normalized angle in the most readable of degrees, arc minutes or
+    /// arc seconds.
+    ///
+    /// Angles of at least one degree, and zero, are shown in degrees. Smaller angles of
+    /// at least one arc minute are shown in arc minutes, and anything smaller in arc seconds.
+    /// The value is rounded to two decimal places.
+    fn pretty(&self) -> String
+        where Self::Scalar: fmt::Display
+    {
+        let hundred: Self::Scalar = cast(100.0).unwrap();
+        let round = |value: Self::Scalar| (value * hundred).round() / hundred;
+        let degrees = Deg::from_angle(self.clone().normalize());
+        if degrees.scalar() == Self::Scalar::zero() || degrees.scalar() >= Self::Scalar::one() {
+            Deg(round(degrees.scalar())).to_string()
+        } else if degrees.scalar() >= Self::Scalar::one() / cast(60.0).unwrap() {
+            ArcMinutes(round(ArcMinutes::from_angle(degrees).scalar())).to_string()
+        } else {
+            ArcSeconds(round(ArcSeconds::from_angle(degrees).scalar())).to_string()
         }
     }
-}
 
-impl_angle!(Deg, 360.0);
-impl_angle!(Gon, 400.0);
-impl_angle!(Rad, consts::PI * 2.0);
-impl_angle!(Turns, 1.0);
-impl_angle!(ArcMinutes, 360.0 * 60.0);
-impl_angle!(ArcSeconds, 360.0 * 3600.0);
+    /// The scalar value of the angle expressed in the unit `U`.
+    ///
+    /// Equivalent to `U::from_angle(self).scalar()`, eg. `Rad(π).scalar_in::<Deg<f64>>()`
+    /// is `180.0`.
+    fn scalar_in<U>(&self) -> Self::Scalar
+        where U: Angle<Scalar = Self::Scalar> + FromAngle<Self>
+    {
+        U::from_angle(self.clone()).scalar()
+    }
 
-impl_from_for_angle!(Deg<T>, Rad<T>);
-impl_from_for_angle!(Deg<T>, Turns<T>);
-impl_from_for_angle!(Deg<T>, Gon<T>);
+    /// Reduce the angle to a reference angle in the first quadrant, for quarter-turn
+    /// trigonometry tables.
+    ///
+    /// Returns the reference angle `r` in `[0, period() / 4)` and three flags: negate
+    /// sine, negate cosine and swap. To reconstruct the sine and cosine of `self`, look
+    /// up `sin(r)` and `cos(r)`, exchange them if swap is set, then negate each as flagged.
+    ///
+    /// A non-finite angle has no quadrant; it produces a NaN reference angle with all
+    /// flags cleared.
+    fn reduce_to_first_quadrant(&self) -> (Self, bool, bool, bool) {
+        if !self.scalar().is_finite() {
+            return (Self::new(Self::Scalar::nan()), false, false, false);
+        }
+        let quarter = Self::quarter_turn().scalar();
+        let normalized = self.clone().normalize().scalar();
+        let quadrant = (normalized / quarter).floor().min(cast(3.0).unwrap());
+        let reference = Self::new((normalized - quadrant * quarter).max(Self::Scalar::zero()));
+        match cast::<_, u8>(quadrant).unwrap() {
+            0 => (reference, false, false, false),
+            1 => (reference, false, true, true),
+            2 => (reference, true, true, false),
+            _ => (reference, true, false, true),
+        }
+    }
 
-impl_from_for_angle!(Gon<T>, Deg<T>);
-impl_from_for_angle!(Gon<T>, Rad<T>);
-impl_from_for_angle!(Gon<T>, Turns<T>);
+    /// Raise the magnitude of the signed angle to `exponent`, preserving its sign.
+    ///
+    /// The angle is first wrapped into `[-period() / 2, period() / 2)`, then mapped to
+    /// `signum(v) * |v|^exponent` in the same unit. Zero always maps to zero.
+    fn pow_magnitude(&self, exponent: Self::Scalar) -> Self {
+        let value = self.wrapped_signed().scalar();
+        if value == Self::Scalar::zero() {
+            Self::new(Self::Scalar::zero())
+        } else {
+            Self::new(value.signum() * value.abs().powf(exponent))
+        }
+    }
 
-impl_from_for_angle!(Rad<T>, Deg<T>);
-impl_from_for_angle!(Rad<T>, Gon<T>);
-impl_from_for_angle!(Rad<T>, Turns<T>);
+    /// Compute the sine of an angle, checking the input in debug builds.
+    ///
+    /// In debug builds this panics if the angle is not finite, or is more than a million
+    /// turns from zero, which usually means a missing `normalize`. In release builds it
+    /// is identical to `sin`.
+    fn sin_checked(self) -> Self::Scalar {
+        debug_check_trig_input(&self);
+        self.sin()
+    }
+    /// Compute the cosine of an angle, checking the input in debug builds like `sin_checked`.
+    fn cos_checked(self) -> Self::Scalar {
+        debug_check_trig_input(&self);
+        self.cos()
+    }
+    /// Compute the tangent of an angle, checking the input in debug builds like `sin_checked`.
+    fn tan_checked(self) -> Self::Scalar {
+        debug_check_trig_input(&self);
+        self.tan()
+    }
+    /// Simultaneously compute sine and cosine, checking the input in debug builds like
+    /// `sin_checked`.
+    fn sin_cos_checked(self) -> (Self::Scalar, Self::Scalar) {
+        debug_check_trig_input(&self);
+        self.sin_cos()
+    }
 
-impl_from_for_angle!(Turns<T>, Deg<T>);
-impl_from_for_angle!(Turns<T>, Gon<T>);
-impl_from_for_angle!(Turns<T>, Rad<T>);
+    /// Compute the length of the arc the angle subtends on a circle of `radius`.
+    ///
+    /// Equal to `radius` times the angle in radians.
+    fn arc_length(&self, radius: Self::Scalar) -> Self::Scalar {
+        radius * Rad::from_angle(self.clone()).scalar()
+    }
 
-impl_from_for_angle!(ArcMinutes<T>, Deg<T>);
-impl_from_for_angle!(ArcSeconds<T>, Deg<T>);
-impl_from_for_angle!(ArcSeconds<T>, ArcMinutes<T>);
+    /// Construct the angle subtending an arc of `length` on a circle of `radius`.
+    ///
+    /// The inverse of `arc_length`: the angle is `length / radius` radians. A `radius` of
+    /// zero follows floating point division, giving an infinite angle for a non-zero
+    /// `length` and NaN for a zero `length`.
+    fn from_arc_length(length: Self::Scalar, radius: Self::Scalar) -> Self {
+        Self::from_radians(length / radius)
+    }
 
-impl<T: Float> Deg<T> {
-    /// Construct a `Deg` instance from base degrees, minutes and seconds.
+    /// Compute the length of the chord spanned by the angle on a circle of `radius`.
     ///
-    /// The opposite of decompose. Equivalent to adding the components together:
+    /// Equal to `2 * radius * sin(self / 2)`.
+    fn chord_length(&self, radius: Self::Scalar) -> Self::Scalar {
+        let two: Self::Scalar = cast(2.0).unwrap();
+        two * radius * Self::new(self.scalar() / two).sin()
+    }
+
+    /// Compute the cosine similarity of two directions, `cos(self - other)`.
     ///
-    /// ```
-    /// #   use angular_units::*;
-    ///     let angle = Deg(50.0) + ArcMinutes(30.0) + ArcSeconds(10.0);
-    ///     assert_eq!(angle, Deg::from_components(Deg(50.0),
-    ///         ArcMinutes(30.0), ArcSeconds(10.0)));
-    /// ```
-    pub fn from_components(degs: Deg<T>, mins: ArcMinutes<T>, secs: ArcSeconds<T>) -> Self {
-        degs + mins + secs
+    /// `1` when aligned, `0` when perpendicular and `-1` when opposite.
+    fn direction_dot<U>(&self, other: &U) -> Self::Scalar
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        Self::new(self.scalar() - other.clone().into_angle().scalar()).cos()
     }
 
-    /// Split an angle in degrees into base degrees, minutes and seconds.
+    /// Rotate a direction given by its sine and cosine by the angle `by`.
     ///
-    /// If the decomposition would not be perfect, seconds will be
-    /// a fractional value.
-    pub fn decompose(self) -> (Deg<T>, ArcMinutes<T>, ArcSeconds<T>) {
-        let sixty: T = cast(60.0).unwrap();
-        let degs = self.0.floor();
-        let rem = self.0 - degs;
-        let mins = (rem * sixty).floor();
-        let rem_s = rem * sixty - mins;
-        let seconds = rem_s * sixty;
+    /// Applies the angle sum identities, returning `(sin(a + by), cos(a + by))` for the
+    /// direction `a`.
+    fn rotate_sincos(sin: Self::Scalar, cos: Self::Scalar, by: Self)
+        -> (Self::Scalar, Self::Scalar)
+    {
+        let (by_sin, by_cos) = by.sin_cos();
+        (sin * by_cos + cos * by_sin, cos * by_cos - sin * by_sin)
+    }
 
-        (Deg(degs), ArcMinutes(mins), ArcSeconds(seconds))
+    /// Whether `self` has made progress along the shortest arc from `start` to `goal`.
+    ///
+    /// True when `self` lies on that arc, past `start` and not beyond `goal`. An angle
+    /// equal to `start`, on the other side of `start`, or that has overshot `goal` is not
+    /// progressing. If `start` and `goal` coincide, nothing is progressing.
+    fn progressing_toward<U, V>(&self, start: U, goal: V) -> bool
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>,
+              V: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let start = start.into_angle();
+        let to_goal = goal.into_angle().delta(&start).scalar();
+        let travelled = self.delta(&start).scalar();
+        to_goal != Self::Scalar::zero() && travelled * to_goal > Self::Scalar::zero()
+            && travelled.abs() <= to_goal.abs()
     }
-}
 
-impl<T: Float> Rad<T> {
-    pub fn pi() -> Rad<T> {
-        Rad(cast(consts::PI).unwrap())
+    /// Clamp the angle to the symmetric limits `[-limit, limit]`, like a joint limit.
+    ///
+    /// The angle is first wrapped into `[-period() / 2, period() / 2)` and then clamped,
+    /// so `Deg(200.0)` becomes `Deg(-160.0)` before clamping, and with a limit of
+    /// `Deg(120.0)` produces `Deg(-120.0)` rather than `Deg(120.0)`.
+    ///
+    /// A NaN angle or limit produces a NaN angle.
+    fn clamp_symmetric(self, limit: Self) -> Self {
+        let limit = limit.scalar().abs();
+        let value = self.wrapped_signed().scalar();
+        if value.is_nan() || limit.is_nan() {
+            return Self::new(Self::Scalar::nan());
+        }
+        Self::new(value.max(-limit).min(limit))
     }
-    pub fn pi_over_2() -> Rad<T> {
-        Rad(cast(consts::PI / 2.0).unwrap())
+
+    /// The normalized direction a quarter turn clockwise (decreasing) from `self`.
+    fn orthogonal_cw(&self) -> Self {
+        Self::new(self.scalar() - Self::quarter_turn().scalar()).normalize()
     }
-    pub fn pi_over_3() -> Rad<T> {
-        Rad(cast(consts::PI / 3.0).unwrap())
+    /// The normalized direction a quarter turn counter-clockwise (increasing) from `self`.
+    fn orthogonal_ccw(&self) -> Self {
+        Self::new(self.scalar() + Self::quarter_turn().scalar()).normalize()
     }
-    pub fn pi_over_4() -> Rad<T> {
-        Rad(cast(consts::PI / 4.0).unwrap())
+    /// The normalized direction a half turn from `self`.
+    ///
+    /// Equivalent to `back_bearing`.
+    fn antipode(&self) -> Self {
+        self.back_bearing()
     }
 }
 
-impl<T, U> IntoAngle<U> for T
-    where U: Angle<Scalar = T::Scalar> + FromAngle<T>,
-          T: Angle
-{
-    type OutputScalar = T::Scalar;
-    fn into_angle(self) -> U {
-        U::from_angle(self)
+/// Angles supporting the standard arithmetic operators.
+///
+/// Implemented automatically for every angle type, this allows generic code
+/// to name a single bound rather than listing each operator.
+///
+/// ```
+/// #   use angular_units::*;
+///     fn average<A: AngleMath<Scalar = f64>>(a: A, b: A) -> A {
+///         (a + b) * 0.5
+///     }
+///     assert_eq!(average(Deg(10.0), Deg(50.0)), Deg(30.0));
+/// ```
+pub trait AngleMath: Angle + Add<Self, Output = Self> + Sub<Self, Output = Self>
+    + Mul<<Self as Angle>::Scalar, Output = Self> + Neg<Output = Self> + num::Zero
+{}
+
+impl<A> AngleMath for A
+    where A: Angle + Add<A, Output = A> + Sub<A, Output = A> + Mul<<A as Angle>::Scalar, Output = A>
+        + Neg<Output = A> + num::Zero
+{}
+
+/// A trait for linear interpolation between angles.
+pub trait Interpolate: Angle {
+    /// Perform a linear interpolation between two angles.
+    ///
+    /// This method will always follow the shortest past between
+    /// the two angles. This means it will go backward if the
+    /// angles are more than a half turn apart. To force the interpolation
+    /// to go forward, use `interpolate_forward`.
+    /// The output is not normalized, and may exceed a
+    /// full turn if it interpolates backward,
+    /// even if both inputs are normalized.
+    /// The angles may be represented in different units.
+    ///
+    /// If the angles are exactly a half turn apart, both paths are equally short and
+    /// the interpolation always goes counter-clockwise (increasing) from `self`. Use
+    /// `interpolate_with_direction` to choose the direction explicitly.
+    ///
+    /// `pos` is not clamped, so values outside of `[0, 1]` extrapolate
+    /// beyond the endpoints. Use `interpolate_clamped` to prevent this.
+    /// A NaN `pos` produces a NaN result; use `try_interpolate` to reject it.
+    fn interpolate<U>(&self, right: &U, pos: Self::Scalar) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>;
+
+    /// Perform a linear interpolation between two angles,
+    /// going forward from `self` to `right`.
+    ///
+    /// Unlike `interpolate` this will always go forward from `self` to `right`,
+    /// even if going backward would take a shorter path. The output is not
+    /// normalized, but should remain normalized if both `self` and `right` are.
+    /// The angles may be represented in different units.
+    fn interpolate_forward<U>(&self, right: &U, pos: Self::Scalar) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>;
+
+    /// Perform a shortest-path interpolation, snapping the result to the nearest
+    /// of `steps` evenly spaced angles around the full turn.
+    ///
+    /// The snapped output is normalized. If `steps` is zero, no snapping is
+    /// performed and the result is the same as `interpolate`.
+    fn interpolate_quantized<U>(&self, right: &U, pos: Self::Scalar, steps: u32) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let value = self.interpolate(right, pos);
+        if steps == 0 {
+            return value;
+        }
+
+        let steps: Self::Scalar = cast(steps).unwrap();
+        let step = Self::period() / steps;
+        let index = (value.normalize().scalar() / step).round();
+        if index >= steps {
+            Self::new(Self::Scalar::zero())
+        } else {
+            Self::new(index * step)
+        }
+    }
+
+    /// Perform a shortest-path interpolation between two angles, normalizing the result.
+    ///
+    /// Equivalent to `interpolate` followed by `normalize`, so the output is always
+    /// in `[0, period())`.
+    fn blend<U>(&self, right: &U, pos: Self::Scalar) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        self.interpolate(right, pos).normalize()
+    }
+
+    /// Perform a linear interpolation between two angles, spinning `extra_turns`
+    /// full rotations along the way.
+    ///
+    /// For non-negative `extra_turns`, the path goes forward from `self` to `right`
+    /// plus `extra_turns` full periods. For negative `extra_turns`, the path instead goes
+    /// backward from `self` to `right` plus `-extra_turns` full periods. At `pos = 1`,
+    /// the result is coterminal with `right`. The output is not normalized.
+    fn interpolate_turns<U>(&self, right: &U, pos: Self::Scalar, extra_turns: i32) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let end = right.clone().into_angle();
+        let extra = cast::<_, Self::Scalar>(extra_turns).unwrap() * Self::period();
+        let distance = if extra_turns >= 0 {
+            Self::new(end.scalar() - self.scalar()).normalize().scalar() + extra
+        } else {
+            extra - Self::new(self.scalar() - end.scalar()).normalize().scalar()
+        };
+
+        Self::new(self.scalar() + distance * pos)
+    }
+
+    /// Perform a shortest-path interpolation between two angles, clamping `pos` to `[0, 1]`.
+    ///
+    /// Unlike `interpolate`, this never extrapolates past either endpoint.
+    fn interpolate_clamped<U>(&self, right: &U, pos: Self::Scalar) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let pos = pos.max(Self::Scalar::zero()).min(Self::Scalar::one());
+        self.interpolate(right, pos)
+    }
+
+    /// Perform a shortest-path interpolation between two angles, rejecting a
+    /// non-finite `pos`.
+    ///
+    /// Returns `None` if `pos` is NaN or infinite, and otherwise the same result as
+    /// `interpolate`.
+    fn try_interpolate<U>(&self, right: &U, pos: Self::Scalar) -> Option<Self>
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        if pos.is_finite() {
+            Some(self.interpolate(right, pos))
+        } else {
+            None
+        }
+    }
+
+    /// Recover the `pos` for which `start.interpolate(&end, pos)` produces `value`.
+    ///
+    /// Uses the same shortest-path convention as `interpolate`. Positions outside
+    /// `[0, 1]` are recovered as long as `value` is within a half turn of the midpoint
+    /// of the arc. If `start` and `end` are the same angle, `0` is returned.
+    fn inverse_interpolate<U, V>(value: &Self, start: U, end: V) -> Self::Scalar
+        where U: IntoAngle<Self, OutputScalar = Self::Scalar>,
+              V: IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let start = start.into_angle();
+        let end = end.into_angle();
+        let origin = start.interpolate(&end, Self::Scalar::zero()).scalar();
+        let span = start.interpolate(&end, Self::Scalar::one()).scalar() - origin;
+        if span == Self::Scalar::zero() {
+            return Self::Scalar::zero();
+        }
+
+        let half_span = span / cast(2.0).unwrap();
+        let offset = Self::new(value.scalar() - origin - half_span).wrapped_signed().scalar();
+        let offset = offset + half_span;
+        offset / span
+    }
+
+    /// Perform a shortest-path interpolation with `pos` eased by the smoothstep
+    /// polynomial `3t² - 2t³`.
+    ///
+    /// `pos` is clamped to `[0, 1]` before easing.
+    fn smoothstep<U>(&self, right: &U, pos: Self::Scalar) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let t = pos.max(Self::Scalar::zero()).min(Self::Scalar::one());
+        let three: Self::Scalar = cast(3.0).unwrap();
+        let two: Self::Scalar = cast(2.0).unwrap();
+        self.interpolate(right, t * t * (three - two * t))
+    }
+
+    /// Perform a shortest-path interpolation with `pos` eased by the smootherstep
+    /// polynomial `6t⁵ - 15t⁴ + 10t³`.
+    ///
+    /// `pos` is clamped to `[0, 1]` before easing.
+    fn smootherstep<U>(&self, right: &U, pos: Self::Scalar) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let t = pos.max(Self::Scalar::zero()).min(Self::Scalar::one());
+        let six: Self::Scalar = cast(6.0).unwrap();
+        let fifteen: Self::Scalar = cast(15.0).unwrap();
+        let ten: Self::Scalar = cast(10.0).unwrap();
+        self.interpolate(right, t * t * t * (t * (t * six - fifteen) + ten))
+    }
+
+    /// Compute the weighted mean of two angles, giving `other` a weight of `weight_other`.
+    ///
+    /// Equivalent to `blend`: the result follows the shortest path and is normalized.
+    /// Intended for fusing two estimates of a heading.
+    fn weighted_mean<U>(&self, other: &U, weight_other: Self::Scalar) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        self.blend(other, weight_other)
+    }
+
+    /// Perform a shortest-path interpolation, also returning the length of the arc
+    /// between the endpoints.
+    ///
+    /// The first element is the same as `interpolate`, and the second is the same as
+    /// `abs_diff`, always in `[0, period() / 2]`.
+    fn interpolate_with_arc<U>(&self, right: &U, pos: Self::Scalar) -> (Self, Self)
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        (self.interpolate(right, pos), self.abs_diff(right))
+    }
+
+    /// Perform a linear interpolation between two angles in a chosen direction.
+    ///
+    /// If `ccw` is true the interpolation goes counter-clockwise (increasing) from
+    /// `self` to `right`, and otherwise clockwise, covering less than a full turn
+    /// either way. The output is not normalized.
+    fn interpolate_with_direction<U>(&self, right: &U, pos: Self::Scalar, ccw: bool) -> Self
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        let end = right.clone().into_angle();
+        let distance = if ccw {
+            Self::new(end.scalar() - self.scalar()).normalize().scalar()
+        } else {
+            -Self::new(self.scalar() - end.scalar()).normalize().scalar()
+        };
+        Self::new(self.scalar() + distance * pos)
+    }
+
+    /// Perform a shortest-path interpolation, converting the result into the unit `R`.
+    ///
+    /// Equivalent to `R::from_angle(self.interpolate(right, pos))`.
+    fn interpolate_into<U, R>(&self, right: &U, pos: Self::Scalar) -> R
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>,
+              R: Angle<Scalar = Self::Scalar> + FromAngle<Self>
+    {
+        R::from_angle(self.interpolate(right, pos))
+    }
+
+    /// Perform a shortest-path interpolation in the intermediate unit `V`.
+    ///
+    /// Both endpoints are converted to `V`, interpolated there, and the result is
+    /// converted back. Blending in a coarse unit such as `Turns` keeps the scalars small
+    /// when `Self` is a fine unit such as `ArcSeconds`.
+    fn interpolate_via<V, U>(&self, right: &U, pos: Self::Scalar) -> Self
+        where V: Interpolate<Scalar = Self::Scalar> + FromAngle<Self>,
+              U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>,
+              Self: FromAngle<V>
+    {
+        let start = V::from_angle(self.clone());
+        let end = V::from_angle(right.clone().into_angle());
+        Self::from_angle(start.interpolate(&end, pos))
+    }
+
+    /// Produce `samples` evenly spaced shortest-path interpolations from `self` to
+    /// `right`, both inclusive, each normalized.
+    ///
+    /// The batch version of `blend`. With `samples` of one, only `self` is returned,
+    /// and with zero the result is empty.
+    fn interpolate_samples<U>(&self, right: &U, samples: usize) -> Vec<Self>
+        where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+    {
+        if samples < 2 {
+            return (0..samples).map(|_| self.clone().normalize()).collect();
+        }
+        let last: Self::Scalar = cast(samples - 1).unwrap();
+        (0..samples)
+            .map(|i| self.blend(right, cast::<_, Self::Scalar>(i).unwrap() / last))
+            .collect()
+    }
+}
+
+macro_rules! impl_angle {
+    ($Struct: ident, $period: expr, $label: expr) => {
+        impl<T: Float> Angle for $Struct<T>
+        {
+            type Scalar = T;
+
+            fn new(value: T) -> $Struct<T> {
+                $Struct(value)
+            }
+
+            fn period() -> T {
+                cast($period).unwrap()
+            }
+
+            fn scalar(&self) -> T {
+                self.0
+            }
+            fn set_scalar(&mut self, value: T) {
+                self.0 = value;
+            }
+            fn is_normalized(&self) -> bool {
+                self.0 >= T::zero() && self.0 < Self::period()
+            }
+
+            fn normalize(self) -> $Struct<T> {
+                if !self.is_normalized() {
+                    // The remainder is exact, even for large magnitudes, but adding
+                    // the period back to a tiny negative remainder can round up to
+                    // exactly one period, which is outside of the domain.
+                    let shifted = self.0 % Self::period();
+                    if shifted < T::zero() {
+                        let wrapped = shifted + Self::period();
+                        if wrapped >= Self::period() {
+                            $Struct(T::zero())
+                        } else {
+                            $Struct(wrapped)
+                        }
+                    } else {
+                        $Struct(shifted)
+                    }
+                } else {
+                    self
+                }
+            }
+
+            fn sin(self) -> T {
+                Rad::from_angle(self).0.sin()
+            }
+            fn cos(self) -> T {
+                Rad::from_angle(self).0.cos()
+            }
+            fn tan(self) -> T {
+                Rad::from_angle(self).0.tan()
+            }
+            fn sin_cos(self) -> (T, T) {
+                Rad::from_angle(self).0.sin_cos()
+            }
+            fn asin(value: T) -> $Struct<T> {
+                $Struct::from_angle(Rad(value.asin()))
+            }
+            fn acos(value: T) -> $Struct<T> {
+                $Struct::from_angle(Rad(value.acos()))
+            }
+            fn atan(value: T) -> $Struct<T> {
+                $Struct::from_angle(Rad(value.atan()))
+            }
+            fn atan2(y: T, x: T) -> $Struct<T> {
+                $Struct::from_angle(Rad(y.atan2(x)))
+            }
+
+            fn full_turn() -> Self {
+                $Struct(Self::period())
+            }
+            fn half_turn() -> Self {
+                $Struct(cast::<_, Self::Scalar>(0.5).unwrap() * Self::period())
+            }
+            fn quarter_turn() -> Self {
+                $Struct(cast::<_, Self::Scalar>(0.25).unwrap() * Self::period())
+            }
+            fn invert(self) -> Self {
+                self + Self::half_turn()
+            }
+            fn reflect_x(self) -> Self {
+                Self::full_turn() - self
+            }
+        }
+
+        impl<T: Float> $Struct<T> {
+            /// Split the angle into its scalar value and the unit label used by `Display`,
+            /// eg. `(90.0, "°")` for `Deg(90.0)`.
+            ///
+            /// Turns have an empty label.
+            pub fn value_and_unit(&self) -> (T, &'static str) {
+                (self.0, $label)
+            }
+        }
+
+        impl<T: fmt::Display> fmt::Display for $Struct<T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                fmt_with_unit(&self.0, $label, f)
+            }
+        }
+
+        impl<T: Float> Interpolate for $Struct<T> {
+            fn interpolate<U>(&self, right: &U, pos: Self::Scalar) -> Self
+                where U: Clone + IntoAngle<Self, OutputScalar=Self::Scalar>
+            {
+                let end = right.clone().into_angle();
+                let forward_distance = (end.0 - self.0).abs();
+                let inv_pos = cast::<_, Self::Scalar>(1.0).unwrap() - pos;
+                
+                let half_turn = Self::half_turn().0;
+                if forward_distance > half_turn || (forward_distance == half_turn && *self > end) {
+                    if *self > end {
+                        $Struct(self.0 * inv_pos + (end.0 + Self::period()) * pos)
+                    } else {
+                        $Struct((self.0 + Self::period()) * inv_pos + end.0 * pos)
+                    }
+                } else {
+                    $Struct(self.0 * inv_pos + end.0 * pos)
+                }
+            }
+
+            fn interpolate_forward<U>(&self, right: &U, pos: Self::Scalar) -> Self
+                where U: Clone + IntoAngle<Self, OutputScalar = Self::Scalar>
+            {
+                let inv_pos = cast::<_, Self::Scalar>(1.0).unwrap() - pos;
+                $Struct(self.0 * inv_pos + right.clone().into_angle().0 * pos)
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<T: Float + approx::AbsDiffEq<Epsilon = T>> approx::AbsDiffEq for $Struct<T>
+        {
+            type Epsilon = T::Epsilon;
+
+            /// The scalar's default epsilon, scaled so that it represents the
+            /// same physical angle in every unit, with radians as the reference.
+            fn default_epsilon() -> Self::Epsilon {
+                T::default_epsilon() * Self::period() / Rad::<T>::period()
+            }
+
+            fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool
+            {
+                let inv_self = self.clone().reflect_x();
+
+                self.0.abs_diff_eq(&other.0, epsilon.clone())
+                || self.0.abs_diff_eq(&other.clone().reflect_x().0,
+                      epsilon.clone())
+                || inv_self.0.abs_diff_eq(&other.0, epsilon)
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<T: Float + approx::RelativeEq<Epsilon = T>> approx::RelativeEq for $Struct<T>
+        {
+            fn default_max_relative() -> Self::Epsilon {
+                T::default_max_relative()
+            }
+
+            fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon, 
+                           max_relative: Self::Epsilon) -> bool {
+                let inv_self = self.clone().reflect_x();
+
+                self.0.relative_eq(&other.0, epsilon.clone(), max_relative.clone())
+                || self.0.relative_eq(&other.clone().reflect_x().0, 
+                      epsilon.clone(), max_relative.clone())
+                || inv_self.0.relative_eq(&other.0, epsilon, max_relative)
+            }
+        }
+
+        #[cfg(feature = "approx")]
+        impl<T: Float + approx::UlpsEq<Epsilon = T>> approx::UlpsEq for $Struct<T>
+        {
+            fn default_max_ulps() -> u32 {
+                T::default_max_ulps()
+            }
+            fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+                let inv_self = self.clone().reflect_x();
+
+                self.0.ulps_eq(&other.0, epsilon.clone(), max_ulps)
+                || self.0.ulps_eq(&other.clone().reflect_x().0, epsilon.clone(), max_ulps)
+                || inv_self.0.ulps_eq(&other.0, epsilon, max_ulps)
+            }
+        }
+
+        impl<T: Rem<T, Output=T>> Rem for $Struct<T> {
+            type Output=$Struct<T>;
+            fn rem(self, rhs: $Struct<T>) -> $Struct<T> {
+                $Struct(self.0 % rhs.0)
+            }
+        }
+
+        impl<T: RemAssign> RemAssign for $Struct<T> {
+            fn rem_assign(&mut self, rhs: $Struct<T>) {
+                self.0 %= rhs.0;
+            }
+        }
+
+        impl<U, T> Add<U> for $Struct<T> 
+            where T: Float + Add<T, Output=T>,
+                  U: IntoAngle<$Struct<T>, OutputScalar=T>
+        {
+            type Output=$Struct<T>;
+            fn add(self, rhs: U) -> $Struct<T> {
+                $Struct(self.0 + rhs.into_angle().0)
+            }
+        }
+
+        impl<U, T> AddAssign<U> for $Struct<T> 
+            where T: Float + AddAssign<T>,
+                  U: IntoAngle<$Struct<T>, OutputScalar=T>
+        {
+            fn add_assign(&mut self, rhs: U) {
+                self.0 += rhs.into_angle().0;
+            }
+        }
+
+        impl<U, T> Sub<U> for $Struct<T> 
+            where T: Float + Sub<T, Output=T>,
+                  U: IntoAngle<$Struct<T>, OutputScalar=T>
+        {
+            type Output=$Struct<T>;
+            fn sub(self, rhs: U) -> $Struct<T> {
+                $Struct(self.0 - rhs.into_angle().0)
+            }
+        }
+
+        impl<U, T> SubAssign<U> for $Struct<T> 
+            where T: Float + SubAssign<T>,
+                  U: IntoAngle<$Struct<T>, OutputScalar=T>
+        {
+            fn sub_assign(&mut self, rhs: U) {
+                self.0 -= rhs.into_angle().0;
+            }
+        }
+        
+        impl<T: Mul<T, Output=T>> Mul<T> for $Struct<T> {
+            type Output=$Struct<T>;
+            fn mul(self, rhs: T) -> $Struct<T> {
+                $Struct(self.0 * rhs)
+            }
+        }
+
+        impl<T: MulAssign<T>> MulAssign<T> for $Struct<T> {
+            fn mul_assign(&mut self, rhs: T) {
+                self.0 *= rhs;
+            }
+        }
+
+        impl<T: Div<T, Output=T>> Div<T> for $Struct<T> {
+            type Output=$Struct<T>;
+            fn div(self, rhs: T) -> $Struct<T> {
+                $Struct(self.0 / rhs)
+            }
+        }
+
+        impl<T: DivAssign<T>> DivAssign<T> for $Struct<T> {
+            fn div_assign(&mut self, rhs: T) {
+                self.0 /= rhs;
+            }
+        }
+
+        impl<T: Neg<Output=T>> Neg for $Struct<T> {
+            type Output=$Struct<T>;
+            fn neg(self) -> $Struct<T> {
+                $Struct(-self.0)
+            }
+        }
+
+        impl<'a, 'b, U, T> Add<&'b U> for &'a $Struct<T>
+            where T: Float,
+                  U: Clone + IntoAngle<$Struct<T>, OutputScalar=T>
+        {
+            type Output=$Struct<T>;
+            fn add(self, rhs: &'b U) -> $Struct<T> {
+                $Struct(self.0 + rhs.clone().into_angle().0)
+            }
+        }
+
+        impl<'a, 'b, U, T> Sub<&'b U> for &'a $Struct<T>
+            where T: Float,
+                  U: Clone + IntoAngle<$Struct<T>, OutputScalar=T>
+        {
+            type Output=$Struct<T>;
+            fn sub(self, rhs: &'b U) -> $Struct<T> {
+                $Struct(self.0 - rhs.clone().into_angle().0)
+            }
+        }
+
+        impl<'a, 'b, T> Mul<&'b T> for &'a $Struct<T>
+            where &'a T: Mul<&'b T, Output=T>
+        {
+            type Output=$Struct<T>;
+            fn mul(self, rhs: &'b T) -> $Struct<T> {
+                $Struct(&self.0 * rhs)
+            }
+        }
+
+        impl<'a, 'b, T> Div<&'b T> for &'a $Struct<T>
+            where &'a T: Div<&'b T, Output=T>
+        {
+            type Output=$Struct<T>;
+            fn div(self, rhs: &'b T) -> $Struct<T> {
+                $Struct(&self.0 / rhs)
+            }
+        }
+
+        impl<'a, T> Neg for &'a $Struct<T>
+            where &'a T: Neg<Output=T>
+        {
+            type Output=$Struct<T>;
+            fn neg(self) -> $Struct<T> {
+                $Struct(-&self.0)
+            }
+        }
+
+        impl<T: Float> num::Zero for $Struct<T> {
+            fn zero() -> $Struct<T> {
+                $Struct(T::zero())
+            }
+            fn is_zero(&self) -> bool {
+                self.0 == T::zero()
+            }
+        }
+
+        impl<T: num::Zero> Default for $Struct<T> {
+            fn default() -> $Struct<T> {
+                $Struct(T::zero())
+            }
+        }
+
+        impl<T, U> FromAngle<U> for $Struct<T>
+            where U: Angle<Scalar=T>,
+                  T: Float,
+        {
+            fn from_angle(from: U) -> $Struct<T> {
+                $Struct(convert_scalar(from.scalar(), U::period(), $Struct::<T>::period()))
+            }
+        }
+    }
+}
+
+macro_rules! impl_from_for_angle {
+    ($from: ty, $to: ty) => {
+        impl<T: Float> From<$from> for $to {
+            fn from(from: $from) -> $to {Self::from_angle(from)}
+        }
+    }
+}
+
+impl_angle!(Deg, 360.0, "°");
+impl_angle!(Gon, 400.0, "gon");
+impl_angle!(Rad, PI * 2.0, "r");
+impl_angle!(Turns, 1.0, "");
+impl_angle!(ArcMinutes, 360.0 * 60.0, "'");
+impl_angle!(ArcSeconds, 360.0 * 3600.0, "\"");
+impl_angle!(Revolutions, 1.0, "rev");
+
+impl_from_for_angle!(Deg<T>, Rad<T>);
+impl_from_for_angle!(Deg<T>, Turns<T>);
+impl_from_for_angle!(Deg<T>, Gon<T>);
+impl_from_for_angle!(Deg<T>, ArcMinutes<T>);
+impl_from_for_angle!(Deg<T>, ArcSeconds<T>);
+
+impl_from_for_angle!(Gon<T>, Deg<T>);
+impl_from_for_angle!(Gon<T>, Rad<T>);
+impl_from_for_angle!(Gon<T>, Turns<T>);
+impl_from_for_angle!(Gon<T>, ArcMinutes<T>);
+impl_from_for_angle!(Gon<T>, ArcSeconds<T>);
+
+impl_from_for_angle!(Rad<T>, Deg<T>);
+impl_from_for_angle!(Rad<T>, Gon<T>);
+impl_from_for_angle!(Rad<T>, Turns<T>);
+impl_from_for_angle!(Rad<T>, ArcMinutes<T>);
+impl_from_for_angle!(Rad<T>, ArcSeconds<T>);
+
+impl_from_for_angle!(Turns<T>, Deg<T>);
+impl_from_for_angle!(Turns<T>, Gon<T>);
+impl_from_for_angle!(Turns<T>, Rad<T>);
+impl_from_for_angle!(Turns<T>, ArcMinutes<T>);
+impl_from_for_angle!(Turns<T>, ArcSeconds<T>);
+
+impl_from_for_angle!(ArcMinutes<T>, Deg<T>);
+impl_from_for_angle!(ArcMinutes<T>, Gon<T>);
+impl_from_for_angle!(ArcMinutes<T>, Rad<T>);
+impl_from_for_angle!(ArcMinutes<T>, Turns<T>);
+impl_from_for_angle!(ArcMinutes<T>, ArcSeconds<T>);
+
+impl_from_for_angle!(ArcSeconds<T>, Deg<T>);
+impl_from_for_angle!(ArcSeconds<T>, Gon<T>);
+impl_from_for_angle!(ArcSeconds<T>, Rad<T>);
+impl_from_for_angle!(ArcSeconds<T>, Turns<T>);
+impl_from_for_angle!(ArcSeconds<T>, ArcMinutes<T>);
+
+impl_from_for_angle!(Revolutions<T>, Deg<T>);
+impl_from_for_angle!(Revolutions<T>, Gon<T>);
+impl_from_for_angle!(Revolutions<T>, Rad<T>);
+impl_from_for_angle!(Revolutions<T>, Turns<T>);
+impl_from_for_angle!(Revolutions<T>, ArcMinutes<T>);
+impl_from_for_angle!(Revolutions<T>, ArcSeconds<T>);
+impl_from_for_angle!(Deg<T>, Revolutions<T>);
+impl_from_for_angle!(Gon<T>, Revolutions<T>);
+impl_from_for_angle!(Rad<T>, Revolutions<T>);
+impl_from_for_angle!(Turns<T>, Revolutions<T>);
+impl_from_for_angle!(ArcMinutes<T>, Revolutions<T>);
+impl_from_for_angle!(ArcSeconds<T>, Revolutions<T>);
+
+macro_rules! impl_from_scalar {
+    ($($Struct: ident),*) => {
+        $(
+            /// Wraps a bare scalar, interpreting it as already being in this unit.
+            impl<T: Float> From<T> for $Struct<T> {
+                fn from(value: T) -> $Struct<T> {
+                    $Struct(value)
+                }
+            }
+        )*
+    }
+}
+
+impl_from_scalar!(Deg, Gon, Rad, Turns, ArcMinutes, ArcSeconds, Revolutions);
+
+macro_rules! impl_scalar_mul {
+    ($scalar: ty; $($Struct: ident),*) => {
+        $(
+            impl Mul<$Struct<$scalar>> for $scalar {
+                type Output = $Struct<$scalar>;
+                fn mul(self, rhs: $Struct<$scalar>) -> $Struct<$scalar> {
+                    $Struct(self * rhs.0)
+                }
+            }
+        )*
+    }
+}
+
+impl_scalar_mul!(f32; Deg, Gon, Rad, Turns, ArcMinutes, ArcSeconds, Revolutions);
+impl_scalar_mul!(f64; Deg, Gon, Rad, Turns, ArcMinutes, ArcSeconds, Revolutions);
+
+impl<T: Float> Deg<T> {
+    /// Construct a `Deg` instance from base degrees, minutes and seconds.
+    ///
+    /// The opposite of decompose. Equivalent to adding the components together:
+    ///
+    /// ```
+    /// #   use angular_units::*;
+    ///     let angle = Deg(50.0) + ArcMinutes(30.0) + ArcSeconds(10.0);
+    ///     assert_eq!(angle, Deg::from_components(Deg(50.0),
+    ///         ArcMinutes(30.0), ArcSeconds(10.0)));
+    /// ```
+    pub fn from_components(degs: Deg<T>, mins: ArcMinutes<T>, secs: ArcSeconds<T>) -> Self {
+        degs + mins + secs
+    }
+
+    /// Construct a `Deg` instance from validated base degrees, minutes and seconds.
+    ///
+    /// Like `from_components`, but returns `AngleError::OutOfRange` if the minutes or
+    /// seconds are negative, `60` or more, or NaN.
+    pub fn checked_from_components(degs: Deg<T>, mins: ArcMinutes<T>, secs: ArcSeconds<T>)
+        -> Result<Self, AngleError>
+    {
+        let valid = T::zero()..cast(60.0).unwrap();
+        if valid.contains(&mins.0) && valid.contains(&secs.0) {
+            Ok(Deg::from_components(degs, mins, secs))
+        } else {
+            Err(AngleError::OutOfRange)
+        }
+    }
+
+    /// Split an angle in degrees into base degrees, minutes and seconds.
+    ///
+    /// If the decomposition would not be perfect, seconds will be
+    /// a fractional value.
+    pub fn decompose(self) -> (Deg<T>, ArcMinutes<T>, ArcSeconds<T>) {
+        let sixty: T = cast(60.0).unwrap();
+        let degs = self.0.floor();
+        let rem = self.0 - degs;
+        let mins = (rem * sixty).floor();
+        let rem_s = rem * sixty - mins;
+        let seconds = rem_s * sixty;
+
+        (Deg(degs), ArcMinutes(mins), ArcSeconds(seconds))
+    }
+
+    /// Normalize the angle, then split it into base degrees, minutes and seconds.
+    ///
+    /// Unlike `decompose`, all components are non-negative, so `Deg(-10.0)`
+    /// decomposes into `Deg(350.0)`, `ArcMinutes(0.0)` and `ArcSeconds(0.0)`.
+    pub fn decompose_normalized(self) -> (Deg<T>, ArcMinutes<T>, ArcSeconds<T>) {
+        self.normalize().decompose()
+    }
+
+    /// Format the angle as degrees, minutes and seconds using custom separators.
+    ///
+    /// Seconds are rounded to `sec_decimals` decimal places, carrying into the
+    /// minutes and degrees if needed. Negative angles are written with a single
+    /// leading sign, unless they round to zero.
+    ///
+    /// ```
+    /// #   use angular_units::*;
+    ///     let angle = Deg(50.0) + ArcMinutes(30.0) + ArcSeconds(10.5);
+    ///     assert_eq!(angle.format_dms(":", ":", "", 2), "50:30:10.50");
+    /// ```
+    pub fn format_dms(&self, deg_sep: &str, min_sep: &str, sec_sep: &str,
+                      sec_decimals: usize) -> String {
+        let (degs, mins, secs) = Deg(self.0.abs()).decompose();
+        let mut degs = degs.0.to_f64().unwrap();
+        let mut mins = mins.0.to_f64().unwrap();
+        let scale = 10f64.powi(sec_decimals as i32);
+        let mut secs = (secs.0.to_f64().unwrap() * scale).round() / scale;
+
+        if secs >= 60.0 {
+            secs -= 60.0;
+            mins += 1.0;
+        }
+        if mins >= 60.0 {
+            mins -= 60.0;
+            degs += 1.0;
+        }
+
+        // Only sign a value that is still non-zero after rounding, to avoid `-0°00'00"`.
+        let is_zero = degs == 0.0 && mins == 0.0 && secs == 0.0;
+        let sign = if self.0 < T::zero() && !is_zero { "-" } else { "" };
+        let width = if sec_decimals > 0 { sec_decimals + 3 } else { 2 };
+        format!("{}{}{}{:02}{}{:0width$.prec$}{}", sign, degs, deg_sep, mins, min_sep, secs,
+            sec_sep, width = width, prec = sec_decimals)
+    }
+}
+
+impl<T: Float> Rad<T> {
+    pub fn pi() -> Rad<T> {
+        Rad(cast(PI).unwrap())
+    }
+    pub fn pi_over_2() -> Rad<T> {
+        Rad(cast(PI / 2.0).unwrap())
+    }
+    pub fn pi_over_3() -> Rad<T> {
+        Rad(cast(PI / 3.0).unwrap())
+    }
+    pub fn pi_over_4() -> Rad<T> {
+        Rad(cast(PI / 4.0).unwrap())
+    }
+}
+
+impl<T: Float> Turns<T> {
+    /// Interpret the angle as a time of day, where a full turn is 24 hours.
+    ///
+    /// The angle is normalized first. Returns the hours, minutes and fractional
+    /// seconds, eg. `Turns(0.25)` is `(6, 0, 0.0)`.
+    pub fn to_time_of_day(&self) -> (u32, u32, f64) {
+        let total: f64 = cast(self.normalize().0 * cast(86400.0).unwrap()).unwrap();
+        let mut hours = (total / 3600.0).floor();
+        let remainder = total - hours * 3600.0;
+        let mut minutes = (remainder / 60.0).floor();
+        let mut seconds = remainder - minutes * 60.0;
+
+        if seconds >= 60.0 {
+            seconds -= 60.0;
+            minutes += 1.0;
+        }
+        if minutes >= 60.0 {
+            minutes -= 60.0;
+            hours += 1.0;
+        }
+        (hours as u32 % 24, minutes as u32, seconds)
+    }
+}
+
+impl<T, U> IntoAngle<U> for T
+    where U: Angle<Scalar = T::Scalar> + FromAngle<T>,
+          T: Angle
+{
+    type OutputScalar = T::Scalar;
+    fn into_angle(self) -> U {
+        U::from_angle(self)
+    }
+}
+/// Format `value` followed by `unit`.
+///
+/// The precision and sign flag apply to the number, while the width, fill and
+/// alignment apply to the combined text, which is right-aligned by default like a number.
+fn fmt_with_unit<T: fmt::Display>(value: &T, unit: &str, f: &mut fmt::Formatter) -> fmt::Result {
+    let number = match (f.precision(), f.sign_plus()) {
+        (Some(prec), true) => format!("{:+.*}", prec, value),
+        (Some(prec), false) => format!("{:.*}", prec, value),
+        (None, true) => format!("{:+}", value),
+        (None, false) => value.to_string(),
+    };
+    let text = number + unit;
+    let padding = f.width().unwrap_or(0).saturating_sub(text.chars().count());
+    let (before, after) = match f.align() {
+        Some(fmt::Alignment::Left) => (0, padding),
+        Some(fmt::Alignment::Center) => (padding / 2, padding - padding / 2),
+        Some(fmt::Alignment::Right) | None => (padding, 0),
+    };
+    let fill = f.fill().to_string();
+    f.write_str(&fill.repeat(before))?;
+    f.write_str(&text)?;
+    f.write_str(&fill.repeat(after))
+}
+
+/// Displays an angle's scalar value without a unit suffix.
+///
+/// Created by `Angle::bare`.
+///
+/// ```
+/// #   use angular_units::*;
+///     assert_eq!(format!("{:.3}", Deg(1.2345).bare()), "1.234");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Bare<A>(pub A);
+
+impl<A> fmt::Display for Bare<A>
+    where A: Angle,
+          A::Scalar: fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        fmt::Display::fmt(&self.0.scalar(), f)
+    }
+}
+
+/// An arc running counter-clockwise from `start` to `end`.
+///
+/// The `Display` impl shows both endpoints and the arc length, honoring the
+/// formatter's precision:
+///
+/// ```
+/// #   use angular_units::*;
+///     let range = AngleRange::new(Deg(10.0), Deg(80.0));
+///     assert_eq!(format!("{:.1}", range), "10.0°..80.0° [70.0° arc]");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AngleRange<A> {
+    pub start: A,
+    pub end: A,
+}
+
+impl<A: Angle> AngleRange<A> {
+    /// Construct a range from its endpoints.
+    pub fn new(start: A, end: A) -> AngleRange<A> {
+        AngleRange { start, end }
+    }
+
+    /// The length of the arc from `start` to `end`, in `[0, period())`.
+    ///
+    /// A range whose `end` is before its `start` wraps through zero.
+    pub fn arc_length(&self) -> A {
+        A::new(self.end.scalar() - self.start.scalar()).normalize()
+    }
+
+    /// Shift both endpoints by the same amount so that `start` is normalized.
+    ///
+    /// The distance from `start` to `end` is preserved, so `end` may lie outside of
+    /// `[0, period())`.
+    pub fn normalize(&self) -> AngleRange<A> {
+        let start = self.start.clone().normalize();
+        let shift = start.scalar() - self.start.scalar();
+        AngleRange::new(start, A::new(self.end.scalar() + shift))
+    }
+}
+
+impl<A> fmt::Display for AngleRange<A>
+    where A: Angle + fmt::Display,
+{
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let arc = self.arc_length();
+        match f.precision() {
+            Some(prec) => write!(f, "{:.*}..{:.*} [{:.*} arc]",
+                                 prec, self.start, prec, self.end, prec, arc),
+            None => write!(f, "{}..{} [{} arc]", self.start, self.end, arc),
+        }
+    }
+}
+
+/// Fluently assemble an angle from parts in mixed units.
+///
+/// Each part is converted and added to a running total, which `build` converts into
+/// the requested unit. A generalization of `Deg::from_components`.
+///
+/// ```
+/// #   use angular_units::*;
+///     let angle = AngleBuilder::new().degrees(50.0).arcminutes(30.0).arcseconds(10.0)
+///         .build::<Deg<f64>>();
+///     assert_eq!(angle, Deg::from_components(Deg(50.0), ArcMinutes(30.0), ArcSeconds(10.0)));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AngleBuilder<T> {
+    total: Deg<T>,
+}
+
+impl<T: Float> AngleBuilder<T> {
+    /// Construct a builder with a total of zero.
+    pub fn new() -> AngleBuilder<T> {
+        AngleBuilder { total: Deg(T::zero()) }
+    }
+
+    /// Add an angle in any unit.
+    pub fn angle<A: Angle<Scalar = T>>(self, angle: A) -> AngleBuilder<T> {
+        AngleBuilder { total: self.total + Deg::from_angle(angle) }
+    }
+
+    /// Add a part in degrees.
+    pub fn degrees(self, value: T) -> AngleBuilder<T> {
+        self.angle(Deg(value))
+    }
+
+    /// Add a part in arc minutes.
+    pub fn arcminutes(self, value: T) -> AngleBuilder<T> {
+        self.angle(ArcMinutes(value))
+    }
+
+    /// Add a part in arc seconds.
+    pub fn arcseconds(self, value: T) -> AngleBuilder<T> {
+        self.angle(ArcSeconds(value))
+    }
+
+    /// Add a part in radians.
+    pub fn radians(self, value: T) -> AngleBuilder<T> {
+        self.angle(Rad(value))
+    }
+
+    /// Add a part in gradians.
+    pub fn gons(self, value: T) -> AngleBuilder<T> {
+        self.angle(Gon(value))
+    }
+
+    /// Add a part in turns.
+    pub fn turns(self, value: T) -> AngleBuilder<T> {
+        self.angle(Turns(value))
+    }
+
+    /// Convert the accumulated total into the unit `A`.
+    pub fn build<A>(self) -> A
+        where A: Angle<Scalar = T> + FromAngle<Deg<T>>
+    {
+        A::from_angle(self.total)
+    }
+}
+
+impl<T: Float> Default for AngleBuilder<T> {
+    fn default() -> AngleBuilder<T> {
+        AngleBuilder::new()
+    }
+}
+
+#[cfg(feature = "euclid")]
+macro_rules! impl_euclid_conversions {
+    ($Struct: ident) => {
+        impl<T: Float> From<euclid::Angle<T>> for $Struct<T> {
+            fn from(from: euclid::Angle<T>) -> $Struct<T> {
+                $Struct::from_angle(Rad(from.radians))
+            }
+        }
+
+        impl<T: Float> From<$Struct<T>> for euclid::Angle<T> {
+            fn from(from: $Struct<T>) -> euclid::Angle<T> {
+                euclid::Angle::radians(Rad::from_angle(from).0)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "euclid")]
+impl_euclid_conversions!(Deg);
+#[cfg(feature = "euclid")]
+impl_euclid_conversions!(Gon);
+#[cfg(feature = "euclid")]
+impl_euclid_conversions!(Rad);
+#[cfg(feature = "euclid")]
+impl_euclid_conversions!(Turns);
+#[cfg(feature = "euclid")]
+impl_euclid_conversions!(ArcMinutes);
+#[cfg(feature = "euclid")]
+impl_euclid_conversions!(ArcSeconds);
+#[cfg(feature = "euclid")]
+impl_euclid_conversions!(Revolutions);
+
+#[cfg(feature = "nalgebra")]
+macro_rules! impl_nalgebra_conversions {
+    ($Struct: ident) => {
+        impl<T: Float + nalgebra::RealField> $Struct<T> {
+            /// Construct a 2D rotation matrix rotating by this angle.
+            pub fn to_rotation2(&self) -> nalgebra::Rotation2<T> {
+                nalgebra::Rotation2::new(Rad::from_angle(*self).0)
+            }
+            /// Construct a unit complex number rotating by this angle.
+            pub fn to_unit_complex(&self) -> nalgebra::UnitComplex<T> {
+                nalgebra::UnitComplex::new(Rad::from_angle(*self).0)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl_nalgebra_conversions!(Deg);
+#[cfg(feature = "nalgebra")]
+impl_nalgebra_conversions!(Gon);
+#[cfg(feature = "nalgebra")]
+impl_nalgebra_conversions!(Rad);
+#[cfg(feature = "nalgebra")]
+impl_nalgebra_conversions!(Turns);
+#[cfg(feature = "nalgebra")]
+impl_nalgebra_conversions!(ArcMinutes);
+#[cfg(feature = "nalgebra")]
+impl_nalgebra_conversions!(ArcSeconds);
+#[cfg(feature = "nalgebra")]
+impl_nalgebra_conversions!(Revolutions);
+
+#[cfg(feature = "nalgebra")]
+impl<T: Float + nalgebra::RealField> From<nalgebra::Rotation2<T>> for Rad<T> {
+    fn from(from: nalgebra::Rotation2<T>) -> Rad<T> {
+        Rad(from.angle())
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl<T: Float + nalgebra::RealField> From<nalgebra::UnitComplex<T>> for Rad<T> {
+    fn from(from: nalgebra::UnitComplex<T>) -> Rad<T> {
+        Rad(from.angle())
+    }
+}
+
+/// The number of points to divide a compass into.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum CompassResolution {
+    /// The cardinal directions, eg. `"N"` and `"E"`.
+    Four,
+    /// The cardinal and intercardinal directions, eg. `"N"` and `"NE"`.
+    Eight,
+    /// The cardinal, intercardinal and secondary intercardinal directions, eg. `"NNE"`.
+    Sixteen,
+}
+
+impl CompassResolution {
+    /// The number of compass points at this resolution.
+    pub fn count(self) -> usize {
+        match self {
+            CompassResolution::Four => 4,
+            CompassResolution::Eight => 8,
+            CompassResolution::Sixteen => 16,
+        }
+    }
+}
+
+const COMPASS_POINTS: [&str; 16] = [
+    "N", "NNE", "NE", "ENE", "E", "ESE", "SE", "SSE",
+    "S", "SSW", "SW", "WSW", "W", "WNW", "NW", "NNW",
+];
+
+const COMPASS32_POINTS: [&str; 32] = [
+    "N", "N by E", "NNE", "NE by N", "NE", "NE by E", "ENE", "E by N",
+    "E", "E by S", "ESE", "SE by E", "SE", "SE by S", "SSE", "S by E",
+    "S", "S by W", "SSW", "SW by S", "SW", "SW by W", "WSW", "W by S",
+    "W", "W by N", "WNW", "NW by W", "NW", "NW by N", "NNW", "N by W",
+];
+
+/// A structured degrees, minutes and seconds representation for serialization.
+///
+/// `degrees`, `minutes` and `seconds` hold the magnitude of the angle, and
+/// `negative` holds its sign.
+#[cfg(feature = "serde")]
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DmsRepr {
+    pub degrees: i32,
+    pub minutes: u32,
+    pub seconds: f64,
+    pub negative: bool,
+}
+
+#[cfg(feature = "serde")]
+impl From<Deg<f64>> for DmsRepr {
+    fn from(from: Deg<f64>) -> DmsRepr {
+        let (degs, mins, secs) = Deg(from.0.abs()).decompose();
+        DmsRepr {
+            degrees: degs.0 as i32,
+            minutes: mins.0 as u32,
+            seconds: secs.0,
+            negative: from.0 < 0.0,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<DmsRepr> for Deg<f64> {
+    fn from(from: DmsRepr) -> Deg<f64> {
+        let magnitude = Deg::from_components(Deg(from.degrees as f64),
+            ArcMinutes(from.minutes as f64), ArcSeconds(from.seconds));
+        if from.negative {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+}
+
+/// An angle in a unit determined at runtime.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum TaggedAngle<T> {
+    Deg(Deg<T>),
+    Gon(Gon<T>),
+    Rad(Rad<T>),
+    Turns(Turns<T>),
+    ArcMinutes(ArcMinutes<T>),
+    ArcSeconds(ArcSeconds<T>),
+    Revolutions(Revolutions<T>),
+}
+
+impl<T: Float> TaggedAngle<T> {
+    /// Convert the angle into a statically known unit.
+    ///
+    /// The stored angle is converted directly, without an intermediate unit.
+    pub fn to_angle<A>(self) -> A
+        where A: Angle<Scalar = T> + FromAngle<Deg<T>> + FromAngle<Gon<T>> + FromAngle<Rad<T>>
+                 + FromAngle<Turns<T>> + FromAngle<ArcMinutes<T>> + FromAngle<ArcSeconds<T>>
+                 + FromAngle<Revolutions<T>>
+    {
+        match self {
+            TaggedAngle::Deg(angle) => A::from_angle(angle),
+            TaggedAngle::Gon(angle) => A::from_angle(angle),
+            TaggedAngle::Rad(angle) => A::from_angle(angle),
+            TaggedAngle::Turns(angle) => A::from_angle(angle),
+            TaggedAngle::ArcMinutes(angle) => A::from_angle(angle),
+            TaggedAngle::ArcSeconds(angle) => A::from_angle(angle),
+            TaggedAngle::Revolutions(angle) => A::from_angle(angle),
+        }
+    }
+}
+
+/// An error which can be returned by fallible angle operations.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum AngleError {
+    /// A conversion could not represent the angle within the requested tolerance.
+    PrecisionLoss,
+    /// A component of the angle was outside of its valid range.
+    OutOfRange,
+    /// The angle's magnitude was too large to be handled precisely.
+    TooLarge,
+    /// The angle was infinite or NaN.
+    NonFinite,
+}
+
+impl fmt::Display for AngleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            AngleError::PrecisionLoss => write!(f, "angle conversion lost too much precision"),
+            AngleError::OutOfRange => write!(f, "angle component out of range"),
+            AngleError::TooLarge => write!(f, "angle magnitude too large"),
+            AngleError::NonFinite => write!(f, "angle is not finite"),
+        }
+    }
+}
+
+impl Error for AngleError {}
+
+/// An error which can be returned when parsing an angle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseAngleError {
+    /// The numeric part of the angle could not be parsed.
+    InvalidNumber(ParseFloatError),
+    /// The string is not a recognized compass point.
+    InvalidCompassPoint,
+}
+
+impl fmt::Display for ParseAngleError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            ParseAngleError::InvalidNumber(ref err) => write!(f, "invalid angle value: {}", err),
+            ParseAngleError::InvalidCompassPoint => write!(f, "invalid compass point"),
+        }
+    }
+}
+
+impl Error for ParseAngleError {}
+
+/// Parse an angle, detecting the unit from its suffix.
+///
+/// The recognized suffixes match the `Display` implementations: `°` for degrees,
+/// `r` for radians, `gon` or `g` for gons, `'` for arc minutes, `"` for arc seconds
+/// and `rev` for revolutions. A bare number is parsed as turns.
+///
+/// ```
+/// #   use angular_units::*;
+///     assert_eq!(parse_any_angle("90°"), Ok(TaggedAngle::Deg(Deg(90.0))));
+///     assert_eq!(parse_any_angle("0.25"), Ok(TaggedAngle::Turns(Turns(0.25))));
+/// ```
+pub fn parse_any_angle(s: &str) -> Result<TaggedAngle<f64>, ParseAngleError> {
+    let s = s.trim();
+    let parse = |value: &str| value.trim().parse::<f64>().map_err(ParseAngleError::InvalidNumber);
+
+    if let Some(value) = s.strip_suffix("rev") {
+        parse(value).map(|v| TaggedAngle::Revolutions(Revolutions(v)))
+    } else if let Some(value) = s.strip_suffix("gon") {
+        parse(value).map(|v| TaggedAngle::Gon(Gon(v)))
+    } else if let Some(value) = s.strip_suffix('°') {
+        parse(value).map(|v| TaggedAngle::Deg(Deg(v)))
+    } else if let Some(value) = s.strip_suffix('r') {
+        parse(value).map(|v| TaggedAngle::Rad(Rad(v)))
+    } else if let Some(value) = s.strip_suffix('g') {
+        parse(value).map(|v| TaggedAngle::Gon(Gon(v)))
+    } else if let Some(value) = s.strip_suffix('\'') {
+        parse(value).map(|v| TaggedAngle::ArcMinutes(ArcMinutes(v)))
+    } else if let Some(value) = s.strip_suffix('"') {
+        parse(value).map(|v| TaggedAngle::ArcSeconds(ArcSeconds(v)))
+    } else {
+        parse(s).map(|v| TaggedAngle::Turns(Turns(v)))
+    }
+}
+
+/// A wrapper for approximately comparing angles across the `0`/`period` seam.
+///
+/// Before comparing, the right-hand angle is moved onto the coterminal value
+/// nearest the left-hand angle, so `Turns(0.9999999)` and `Turns(0.0)` compare
+/// as approximately equal.
+///
+/// ```
+/// # #[macro_use] extern crate approx;
+/// # extern crate angular_units;
+/// # use angular_units::*;
+/// # fn main() {
+/// assert_relative_eq!(WrapApprox(Turns(0.9999999)), WrapApprox(Turns(0.0)), epsilon=1e-5);
+/// # }
+/// ```
+#[cfg(feature = "approx")]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct WrapApprox<A>(pub A);
+
+#[cfg(feature = "approx")]
+impl<A: Angle> WrapApprox<A> {
+    fn aligned_scalars(&self, other: &Self) -> (A::Scalar, A::Scalar) {
+        let difference = A::new(other.0.scalar() - self.0.scalar()).wrapped_signed();
+        (self.0.scalar(), self.0.scalar() + difference.scalar())
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<A> approx::AbsDiffEq for WrapApprox<A>
+    where A: Angle,
+          A::Scalar: approx::AbsDiffEq,
+{
+    type Epsilon = <A::Scalar as approx::AbsDiffEq>::Epsilon;
+
+    fn default_epsilon() -> Self::Epsilon {
+        A::Scalar::default_epsilon()
+    }
+
+    fn abs_diff_eq(&self, other: &Self, epsilon: Self::Epsilon) -> bool {
+        let (left, right) = self.aligned_scalars(other);
+        left.abs_diff_eq(&right, epsilon)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<A> approx::RelativeEq for WrapApprox<A>
+    where A: Angle,
+          A::Scalar: approx::RelativeEq,
+{
+    fn default_max_relative() -> Self::Epsilon {
+        A::Scalar::default_max_relative()
+    }
+
+    fn relative_eq(&self, other: &Self, epsilon: Self::Epsilon,
+                   max_relative: Self::Epsilon) -> bool {
+        let (left, right) = self.aligned_scalars(other);
+        left.relative_eq(&right, epsilon, max_relative)
+    }
+}
+
+#[cfg(feature = "approx")]
+impl<A> approx::UlpsEq for WrapApprox<A>
+    where A: Angle,
+          A::Scalar: approx::UlpsEq,
+{
+    fn default_max_ulps() -> u32 {
+        A::Scalar::default_max_ulps()
+    }
+
+    fn ulps_eq(&self, other: &Self, epsilon: Self::Epsilon, max_ulps: u32) -> bool {
+        let (left, right) = self.aligned_scalars(other);
+        left.ulps_eq(&right, epsilon, max_ulps)
+    }
+}
+
+/// An angle that compares and hashes by its normalized value.
+///
+/// The stored angle is normalized on construction. Equality and hashing quantize
+/// the normalized value to a fixed grid of `2^40` steps per turn, so coterminal
+/// angles such as `Deg(370.0)` and `Deg(10.0)` are the same key. Angles that are
+/// not finite all share a single key, so they are equal to each other and to no
+/// finite angle.
+///
+/// ```
+/// #   use angular_units::*;
+///     assert_eq!(Normalized::new(Deg(370.0)), Normalized::new(Deg(10.0)));
+///     assert_eq!(*Normalized::new(Deg(-90.0)), Deg(270.0));
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Normalized<A>(A);
+
+impl<A: Angle> Normalized<A> {
+    const QUANTIZATION_BITS: u32 = 40;
+    const NON_FINITE_KEY: u64 = u64::MAX;
+
+    /// Wrap an angle, normalizing it.
+    pub fn new(angle: A) -> Normalized<A> {
+        Normalized(angle.normalize())
+    }
+
+    /// Unwrap the normalized angle.
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+
+    fn key(&self) -> u64 {
+        self.0.to_fixed_bits(Self::QUANTIZATION_BITS).unwrap_or(Self::NON_FINITE_KEY)
+    }
+}
+
+impl<A: Angle> From<A> for Normalized<A> {
+    fn from(angle: A) -> Normalized<A> {
+        Normalized::new(angle)
+    }
+}
+
+impl<A: Angle> Deref for Normalized<A> {
+    type Target = A;
+
+    fn deref(&self) -> &A {
+        &self.0
+    }
+}
+
+impl<A: Angle> PartialEq for Normalized<A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl<A: Angle> Eq for Normalized<A> {}
+
+impl<A: Angle> Hash for Normalized<A> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.key().hash(state);
+    }
+}
+
+/// An iterator adapter that normalizes each angle it yields.
+///
+/// Created by `NormalizeIter::normalized`.
+#[derive(Clone, Debug)]
+pub struct NormalizedIter<I> {
+    iter: I,
+}
+
+impl<I> Iterator for NormalizedIter<I>
+    where I: Iterator,
+          I::Item: Angle,
+{
+    type Item = I::Item;
+
+    fn next(&mut self) -> Option<I::Item> {
+        self.iter.next().map(Angle::normalize)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// Extension trait adding lazy normalization to iterators over angles.
+///
+/// ```
+/// #   use angular_units::*;
+///     let wrapped: Vec<_> = vec![Deg(370.0), Deg(-90.0)].into_iter().normalized().collect();
+///     assert_eq!(wrapped, vec![Deg(10.0), Deg(270.0)]);
+/// ```
+pub trait NormalizeIter: Iterator + Sized {
+    /// Normalize each angle as it is yielded.
+    ///
+    /// A lazy alternative to `wrap_all`.
+    fn normalized(self) -> NormalizedIter<Self>;
+}
+
+impl<I> NormalizeIter for I
+    where I: Iterator,
+          I::Item: Angle,
+{
+    fn normalized(self) -> NormalizedIter<Self> {
+        NormalizedIter { iter: self }
+    }
+}
+
+/// An angle whose arithmetic operators normalize their results.
+///
+/// Mirrors `std::num::Wrapping`: `Add` and `Sub` on the bare angle types do not
+/// normalize, but on `Wrapping` they do, keeping long accumulation chains bounded.
+/// The stored angle is normalized on construction.
+///
+/// ```
+/// #   use angular_units::*;
+///     let sum = Wrapping::new(Deg(350.0)) + Wrapping::new(Deg(20.0));
+///     assert_eq!(sum.into_inner(), Deg(10.0));
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+pub struct Wrapping<A>(A);
+
+impl<A: Angle> Wrapping<A> {
+    /// Wrap an angle, normalizing it.
+    pub fn new(angle: A) -> Wrapping<A> {
+        Wrapping(angle.normalize())
+    }
+
+    /// Unwrap the normalized angle.
+    pub fn into_inner(self) -> A {
+        self.0
+    }
+}
+
+impl<A: Angle> From<A> for Wrapping<A> {
+    fn from(angle: A) -> Wrapping<A> {
+        Wrapping::new(angle)
+    }
+}
+
+impl<A: Angle> Add for Wrapping<A> {
+    type Output = Wrapping<A>;
+
+    fn add(self, rhs: Wrapping<A>) -> Wrapping<A> {
+        Wrapping(A::new(self.0.scalar() + rhs.0.scalar()).normalize())
+    }
+}
+
+impl<A: Angle> Sub for Wrapping<A> {
+    type Output = Wrapping<A>;
+
+    fn sub(self, rhs: Wrapping<A>) -> Wrapping<A> {
+        Wrapping(A::new(self.0.scalar() - rhs.0.scalar()).normalize())
+    }
+}
+
+impl<A: Angle> AddAssign for Wrapping<A> {
+    fn add_assign(&mut self, rhs: Wrapping<A>) {
+        *self = self.clone() + rhs;
+    }
+}
+
+impl<A: Angle> SubAssign for Wrapping<A> {
+    fn sub_assign(&mut self, rhs: Wrapping<A>) {
+        *self = self.clone() - rhs;
+    }
+}
+
+/// Construct an angle in any unit from base degrees, minutes and seconds.
+///
+/// A generalization of `Deg::from_components`: the components are combined in degrees
+/// and then converted to `A`.
+///
+/// ```
+/// #   use angular_units::*;
+///     let half: Rad<f64> = from_dms(Deg(180.0), ArcMinutes(0.0), ArcSeconds(0.0));
+///     assert_eq!(half, Rad(std::f64::consts::PI));
+/// ```
+pub fn from_dms<T, A>(degs: Deg<T>, mins: ArcMinutes<T>, secs: ArcSeconds<T>) -> A
+    where T: Float,
+          A: Angle<Scalar = T> + FromAngle<Deg<T>>,
+{
+    A::from_angle(Deg::from_components(degs, mins, secs))
+}
+
+/// Split each angle in a slice into base degrees, minutes and seconds.
+///
+/// Equivalent to calling `Deg::decompose` on each element.
+pub fn decompose_all<T: Float>(degs: &[Deg<T>]) -> Vec<(Deg<T>, ArcMinutes<T>, ArcSeconds<T>)> {
+    degs.iter().map(|deg| deg.decompose()).collect()
+}
+
+/// Compute the mean of a collection of angles.
+///
+/// Note that because angles are circular, a standard summation and dividing by `len()`
+/// is _not_ valid, even if all angles are normalized.
+pub fn mean<T, Scalar, Out>(iter: T) -> Out
+    where T: IntoIterator,
+          T::Item: IntoAngle<Rad<Scalar>, OutputScalar=Scalar>,
+          Scalar: Float + AddAssign,
+          Out: Angle<Scalar=Scalar> + FromAngle<Rad<Scalar>>,
+{
+    let mut sum_of_sines: Scalar = cast(0.0).unwrap();
+    let mut sum_of_cosines: Scalar = cast(0.0).unwrap();
+
+    for angle in iter.into_iter() {
+        let intermediate_angle: Rad<Scalar> = angle.into_angle();
+        let (sin, cos) = intermediate_angle.sin_cos();
+        sum_of_sines += sin;
+        sum_of_cosines += cos;
+    }
+
+    Out::atan2(sum_of_sines, sum_of_cosines).normalize()
+}
+
+/// Compute the circular mean of bare scalar angles in degrees.
+///
+/// A convenience over `mean` for untyped values. The result is normalized into
+/// `[0, 360)`, and an empty slice produces `0`.
+pub fn circular_mean_degrees(degrees: &[f64]) -> f64 {
+    let result: Deg<f64> = mean(degrees.iter().map(|&value| Deg(value)));
+    result.0
+}
+
+/// Compute the circular mean of bare scalar angles in radians.
+///
+/// A convenience over `mean` for untyped values. The result is normalized into
+/// `[0, 2π)`, and an empty slice produces `0`.
+pub fn circular_mean_radians(radians: &[f64]) -> f64 {
+    let result: Rad<f64> = mean(radians.iter().map(|&value| Rad(value)));
+    result.0
+}
+
+/// Online circular statistics over a stream of angles.
+///
+/// Only the summed sine and cosine components are stored, so each `push` is O(1).
+///
+/// ```
+/// #   use angular_units::*;
+///     let mut stats = AngleStats::new();
+///     stats.push(Deg(350.0));
+///     stats.push(Deg(10.0));
+///     assert!(stats.circular_mean().is_near_zero(1e-9));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct AngleStats<A: Angle> {
+    sum_of_sines: A::Scalar,
+    sum_of_cosines: A::Scalar,
+    count: usize,
+}
+
+impl<A: Angle> AngleStats<A> {
+    /// Construct an empty accumulator.
+    pub fn new() -> AngleStats<A> {
+        AngleStats {
+            sum_of_sines: cast(0.0).unwrap(),
+            sum_of_cosines: cast(0.0).unwrap(),
+            count: 0,
+        }
+    }
+
+    /// Add an angle to the statistics.
+    pub fn push(&mut self, angle: A) {
+        let (sin, cos) = angle.sin_cos();
+        self.sum_of_sines = self.sum_of_sines + sin;
+        self.sum_of_cosines = self.sum_of_cosines + cos;
+        self.count += 1;
+    }
+
+    /// The number of angles pushed so far.
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// The normalized circular mean of the angles pushed so far, as computed by `mean`.
+    ///
+    /// With no angles pushed, zero is returned.
+    pub fn circular_mean(&self) -> A {
+        A::atan2(self.sum_of_sines, self.sum_of_cosines).normalize()
+    }
+
+    /// The mean resultant length, in `[0, 1]`.
+    ///
+    /// Values near `1` indicate tightly clustered angles, and values near `0` widely
+    /// dispersed ones. With no angles pushed, zero is returned.
+    pub fn resultant_length(&self) -> A::Scalar {
+        if self.count == 0 {
+            return cast(0.0).unwrap();
+        }
+        self.sum_of_sines.hypot(self.sum_of_cosines) / cast(self.count).unwrap()
+    }
+}
+
+impl<A: Angle> Default for AngleStats<A> {
+    fn default() -> AngleStats<A> {
+        AngleStats::new()
+    }
+}
+
+/// Find the smallest arc enclosing a collection of angles.
+///
+/// Returns the clockwise-most and counterclockwise-most angles bounding the arc,
+/// both normalized, such that going counterclockwise from the first to the second
+/// passes through every input angle. The arc is found by removing the largest gap
+/// between neighboring angles. Returns `None` if `angles` is empty.
+pub fn angular_extent<A: Angle>(angles: &[A]) -> Option<(A, A)> {
+    if angles.is_empty() {
+        return None;
+    }
+
+    let mut sorted: Vec<A> = angles.iter().cloned().map(|a| a.normalize()).collect();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(Ordering::Equal));
+
+    let last = sorted.len() - 1;
+    let mut gap_end = 0;
+    let mut largest_gap = sorted[0].scalar() + A::period() - sorted[last].scalar();
+    for i in 1..sorted.len() {
+        let gap = sorted[i].scalar() - sorted[i - 1].scalar();
+        if gap > largest_gap {
+            largest_gap = gap;
+            gap_end = i;
+        }
+    }
+
+    let gap_start = if gap_end == 0 { last } else { gap_end - 1 };
+    Some((sorted[gap_end].clone(), sorted[gap_start].clone()))
+}
+
+/// Sum a collection of angles, normalizing the total.
+///
+/// The angles are added without intermediate normalization, and the result is
+/// normalized once at the end. Since composing planar rotations adds their angles,
+/// this also applies a sequence of rotations; an empty sequence is the zero rotation.
+pub fn sum_normalized<A: Angle, I: IntoIterator<Item = A>>(iter: I) -> A {
+    let mut total: A::Scalar = cast(0.0).unwrap();
+    for angle in iter {
+        total = total + angle.scalar();
+    }
+    A::new(total).normalize()
+}
+
+/// Apply a sequence of rotations, returning the normalized total rotation.
+///
+/// Equivalent to `sum_normalized`; an empty sequence is the zero rotation.
+pub fn compose_rotations<A: Angle, I: IntoIterator<Item = A>>(iter: I) -> A {
+    sum_normalized(iter)
+}
+
+/// Integrate uniformly spaced angular rate samples using the trapezoidal rule.
+///
+/// Each sample is an angle per unit time, and `dt` is the time between samples.
+/// The accumulated angle is not normalized. With fewer than two samples, zero is returned.
+pub fn integrate_trapezoid<A: Angle>(rates: &[A], dt: A::Scalar) -> A {
+    let half: A::Scalar = cast(0.5).unwrap();
+    let mut total: A::Scalar = cast(0.0).unwrap();
+    for pair in rates.windows(2) {
+        total = total + (pair[0].scalar() + pair[1].scalar()) * half * dt;
+    }
+    A::new(total)
+}
+
+/// Normalize every angle in a sequence into the standard domain.
+pub fn wrap_all<A: Angle>(seq: &[A]) -> Vec<A> {
+    seq.iter().cloned().map(|angle| angle.normalize()).collect()
+}
+
+/// Compute the running totals of a sequence of angles.
+///
+/// Element `i` of the output is the sum of `deltas[0..=i]`. The totals are not
+/// normalized, so they can exceed a full turn.
+pub fn cumulative<A: Angle + Clone>(deltas: &[A]) -> Vec<A> {
+    let mut total: A::Scalar = cast(0.0).unwrap();
+    deltas.iter().map(|delta| {
+        total = total + delta.scalar();
+        A::new(total)
+    }).collect()
+}
+
+/// Sample a list of `(time, angle)` keyframes at time `t`.
+///
+/// The keyframes must be sorted by time. The two keyframes surrounding `t` are
+/// blended with `Interpolate::interpolate`, taking the shortest path. Times outside
+/// the keyframe range clamp to the first or last angle. Returns `None` if `keys` is empty
+/// or `t` is NaN.
+pub fn sample_keyframes<A: Interpolate + Clone>(keys: &[(A::Scalar, A)], t: A::Scalar) -> Option<A> {
+    let (first, last) = match (keys.first(), keys.last()) {
+        (Some(first), Some(last)) => (first, last),
+        _ => return None,
+    };
+    if t.is_nan() {
+        return None;
+    }
+    if t <= first.0 {
+        return Some(first.1.clone());
+    }
+    if t >= last.0 {
+        return Some(last.1.clone());
+    }
+
+    let next = keys.iter().position(|key| key.0 > t)?;
+    let (start_time, ref start) = keys[next - 1];
+    let (end_time, ref end) = keys[next];
+    Some(start.interpolate(end, (t - start_time) / (end_time - start_time)))
+}
+
+/// Smooth a sequence of angles with a circular exponential moving average.
+///
+/// Each output is the previous output blended toward the current input by `alpha`
+/// using `Interpolate::blend`, so the average follows the shortest path and stays
+/// normalized. The first output is the first input, normalized.
+pub fn running_blend<A: Interpolate + Clone>(seq: &[A], alpha: A::Scalar) -> Vec<A> {
+    let mut output: Vec<A> = Vec::with_capacity(seq.len());
+    for angle in seq {
+        let next = match output.last() {
+            Some(prev) => prev.blend(angle, alpha),
+            None => angle.clone().normalize(),
+        };
+        output.push(next);
+    }
+    output
+}
+
+/// Compute the signed turn between each pair of consecutive headings.
+///
+/// The output has one fewer element than `headings`, and each delta is the shortest
+/// difference `headings[i + 1] - headings[i]`, in `[-period() / 2, period() / 2)`.
+/// Fewer than two headings produce an empty vector.
+pub fn turn_deltas<A: Angle>(headings: &[A]) -> Vec<A> {
+    headings.windows(2)
+        .map(|pair| A::new(pair[1].scalar() - pair[0].scalar()).wrapped_signed())
+        .collect()
+}
+
+/// Compute the normalized direction from the point `from` toward the point `to`.
+///
+/// Equivalent to `Angle::from_point` applied to the displacement `to - from`. If the
+/// points are identical the direction is undefined and a zero angle is returned.
+pub fn direction_to<A: Angle>(from: (A::Scalar, A::Scalar), to: (A::Scalar, A::Scalar)) -> A {
+    A::from_point((to.0 - from.0, to.1 - from.1))
+}
+
+/// Sort points in place by their normalized bearing from `center`.
+///
+/// Points are ordered counter-clockwise starting from the positive x axis, using
+/// `direction_to`. A point equal to `center` has a bearing of zero.
+pub fn sort_by_bearing<A: Angle>(center: (A::Scalar, A::Scalar), points: &mut [(A::Scalar, A::Scalar)]) {
+    points.sort_by(|&left, &right| {
+        let left: A = direction_to(center, left);
+        let right: A = direction_to(center, right);
+        left.partial_cmp(&right).unwrap_or(Ordering::Equal)
+    });
+}
+
+/// Find where a signal of angle errors crosses zero.
+///
+/// Returns each index `i` where `seq[i]` and `seq[i + 1]` have strictly opposite signs
+/// after wrapping into `[-period() / 2, period() / 2)`. Values of exactly zero do not
+/// count as either sign. Fewer than two angles produce an empty vector.
+pub fn sign_changes<A: Angle>(seq: &[A]) -> Vec<usize> {
+    let zero: A::Scalar = cast(0.0).unwrap();
+    seq.windows(2)
+        .enumerate()
+        .filter(|&(_, pair)| pair[0].wrapped_signed().scalar() * pair[1].wrapped_signed().scalar() < zero)
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Count how many angles fall into each of `bins` equal sectors of a full turn.
+///
+/// Bin `i` starts at `i * period() / bins` and covers `period() / bins`, so bin 0 starts
+/// at zero rather than being centered on it. Each sector includes its start but not its
+/// end. Angles are normalized before binning, and angles that are not finite are skipped
+/// rather than counted. Zero bins produce an empty vector.
+pub fn angle_histogram<A: Angle>(angles: &[A], bins: usize) -> Vec<usize> {
+    let mut counts = vec![0; bins];
+    if bins == 0 {
+        return counts;
+    }
+    let width = A::period() / cast(bins).unwrap();
+    for angle in angles.iter().filter(|angle| angle.scalar().is_finite()) {
+        let index = cast::<_, usize>((angle.normalized_ref().scalar() / width).floor()).unwrap();
+        counts[index.min(bins - 1)] += 1;
+    }
+    counts
+}
+
+/// Compute the angle swept per second by a shaft turning at `rpm` revolutions per minute.
+pub fn rpm_to_angle_per_second<A: Angle>(rpm: A::Scalar) -> A {
+    let seconds_per_minute: A::Scalar = cast(60.0).unwrap();
+    A::new(rpm / seconds_per_minute * A::period())
+}
+
+/// Assert that two angles are equal to within `epsilon`, accounting for wrapping.
+///
+/// The signed shortest difference between the angles is compared against `epsilon`,
+/// so angles on either side of the `0`/`period` seam compare as equal. The angles may
+/// be in different units, in which case `epsilon` is in the units of `left`.
+///
+/// ```
+/// # #[macro_use] extern crate angular_units;
+/// # use angular_units::*;
+/// # fn main() {
+/// assert_angle_eq!(Deg(359.999), Deg(0.0), epsilon = 1e-2);
+/// # }
+/// ```
+#[macro_export]
+macro_rules! assert_angle_eq {
+    ($left: expr, $right: expr, epsilon = $epsilon: expr) => {{
+        let (left, right, epsilon) = (&$left, &$right, $epsilon);
+        let difference = $crate::Angle::scalar(&$crate::Angle::delta(left, right));
+        if !(difference <= epsilon && -epsilon <= difference) {
+            panic!("assert_angle_eq!({}, {}, epsilon = {})
+
+    left       = {:?}
+    right      = {:?}
+    difference = {:?}
+
+",
+                stringify!($left), stringify!($right), stringify!($epsilon),
+                left, right, difference);
+        }
+    }};
+}
+
+/// Angles of more turns than this are flagged by the `_checked` trig methods.
+const MAX_CHECKED_TURNS: f64 = 1e6;
+
+fn debug_check_trig_input<A: Angle>(angle: &A) {
+    debug_assert!(angle.scalar().is_finite(), "trigonometric input is not finite");
+    debug_assert!(angle.scalar().abs() / A::period() <= cast(MAX_CHECKED_TURNS).unwrap(),
+                  "trigonometric input exceeds {} turns; is a normalize missing?",
+                  MAX_CHECKED_TURNS);
+}
+
+/// Convert a scalar between units with the given periods.
+fn convert_scalar<T: Float>(value: T, from_period: T, to_period: T) -> T {
+    // Units whose periods are a whole multiple of each other convert
+    // with a single exact factor to avoid compounding rounding error.
+    if to_period >= from_period {
+        let ratio = to_period / from_period;
+        if ratio == ratio.round() {
+            return value * ratio;
+        }
+    } else {
+        let ratio = from_period / to_period;
+        if ratio == ratio.round() {
+            return value / ratio;
+        }
+    }
+
+    value * to_period / from_period
+}
+
+/// Find the nearest of `count` equal compass sectors to the angle, with sector 0
+/// centered on zero. Returns `None` if the angle is not finite.
+fn compass_sector<A: Angle>(angle: &A, count: usize) -> Option<usize> {
+    if !angle.scalar().is_finite() {
+        return None;
+    }
+    let sector = A::period() / cast(count).unwrap();
+    let index: usize = cast((angle.clone().normalize().scalar() / sector).round())?;
+    Some(index % count)
+}
+
+fn cast<T: NumCast, U: NumCast>(from: T) -> Option<U> {
+    U::from(from)
+}
+
+#[cfg(test)]
+mod test {
+    #[cfg(feature = "serde")]
+    extern crate serde_test;
+
+    use std::f64::consts;
+    use std::f64;
+    use super::*;
+
+    #[test]
+    fn test_construct() {
+        assert_relative_eq!(Deg(50.0), Deg::new(50.0));
+        let mut a1 = Deg(100.0);
+        let scalar = a1.scalar();
+        a1.set_scalar(scalar + 150.0);
+        assert_relative_eq!(a1, Deg(250.0));
+    }
+
+    #[test]
+    fn test_convert() {
+        assert_relative_eq!(Deg(2.0), ArcMinutes(120.0).into_angle(), epsilon=1e-6);
+        assert_relative_eq!(Gon(2.222222), ArcMinutes(120.0).into_angle(), epsilon=1e-6);
+        assert_relative_eq!(ArcMinutes(0.5), ArcSeconds(30.0).into_angle(), epsilon=1e-6);
+        assert_relative_eq!(Deg(30.0) + ArcMinutes(30.0) + ArcSeconds(30.0), 
+            Deg(30.50833333333), epsilon=1e-6);
+        assert_relative_eq!(Deg(180.0), Rad(consts::PI).into_angle(), epsilon=1e-6);
+        assert_relative_eq!(Deg(90.0), Turns(0.25).into_angle(), epsilon=1e-6);
+        assert_relative_eq!(Rad(consts::PI / 2.0), Turns(0.25).into_angle(), epsilon=1e-6);
+        assert_relative_eq!(Deg(10.0), ArcMinutes(600.0).into_angle(), epsilon=1e-6);
+        assert_relative_eq!(Rad(consts::PI / 2.0), ArcMinutes(5400.0).into_angle(), epsilon=1e-6);
+        assert_relative_eq!(Deg(90.0), Gon(100.0).into_angle(), epsilon=1e-6);
+        assert_relative_eq!(Rad(consts::PI / 4.0), Gon(50.0).into_angle(), epsilon=1e-6);
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        {
+            let a1 = Rad(2.0);
+            let a2 = Deg(100.0);
+
+            let a3 = a2 + a1;
+            assert_relative_eq!(a3.0, 214.59, epsilon=1e-2);
+
+            let a4 = Deg(50.0);
+            let a5 = a2 + a4;
+            assert_ulps_eq!(a5.0, 150.0);
+
+            let mut a6 = Deg(123.0);
+            a6 += Deg(10.0);
+            a6 += Rad::pi();
+            assert_ulps_eq!(a6.0, 313.0);
+
+            let a7 = Deg(50.0);
+            assert_ulps_eq!(a7 * 2.0, Deg(100.0));
+        }
+        {
+            let a1 = Rad(2.0);
+            let a2 = a1 % Rad(1.5);
+            assert_ulps_eq!(a2, Rad(0.5));
+            assert_ulps_eq!(Rad(1.0) * 2.0, Rad(2.0));
+            assert_ulps_eq!(Rad(consts::PI * 2.0) / 2.0, Rad(consts::PI));
+        }
+        {
+            let a10 = Gon(15.0);
+            let a11 = Deg(43.0);
+            let a12 = a11 + a10;
+            assert_relative_eq!(a12.0, 56.5, epsilon=1e-2);
+            let a13 = a10 + a11;
+            assert_relative_eq!(a13.0, 62.7778, epsilon=1e-2);
+        }
+    }
+
+    #[test]
+    fn test_trig() {
+        assert_ulps_eq!(Deg(0.0).sin(), 0.0);
+        assert_ulps_eq!(Gon(0.0).sin(), 0.0);
+        assert_ulps_eq!(Rad(consts::PI / 2.0).sin(), 1.0);
+        assert_ulps_eq!(Deg(90.0).sin(), 1.0);
+        assert_ulps_eq!(Deg(45.0).tan(), 1.0);
+        assert_relative_eq!(Deg(405.0).tan(), 1.0, epsilon=1e-6);
+        assert_relative_eq!(Gon(450.0).tan(), 1.0, epsilon=1e-6);
+        let a1 = Rad(consts::PI * 1.25);
+        assert_relative_eq!(a1.cos(), -f64::sqrt(2.0) / 2.0, epsilon=1e-6);
+        assert_relative_eq!(a1.cos(), Deg(135.0).cos(), epsilon=1e-6);
+        assert_relative_eq!(a1.cos(), Gon(150.0).cos(), epsilon=1e-6);
+
+        assert_relative_eq!(Deg::acos(1.0), Deg(0.0));
+        assert_relative_eq!(Deg::acos(0.0), Deg(90.0));
+        assert_relative_eq!(Deg::acos(0.0), Deg::from(Gon(100.0)));
+        assert_relative_eq!(Rad::acos(0.0), Rad::pi_over_2());
+    }
+
+    #[test]
+    fn test_equality() {
+        let a1 = Rad(2.0);
+        assert_ulps_eq!(a1, Rad(2.0));
+        assert_ulps_eq!(Deg(200.0), Deg(200.0));
+        assert!(!(Deg(200.0) == Deg(100.0)));
+
+        assert!(Deg(200.0) < Deg(300.0));
+        assert!(Deg(250.0) > Deg(100.0));
+
+        assert_relative_eq!(Deg(359.999999), Deg(0.0), epsilon=1e-4);
+        assert_ulps_eq!(Deg(359.999999), Deg(0.0), epsilon=1e-4);
+        assert_ulps_eq!(Deg(359.99999), Deg(0.0), epsilon=1e-4);
+    }
+
+    #[test]
+    fn test_normalize() {
+        let mut a1 = Deg(200.0);
+        a1 += Deg(300.0);
+        assert_ulps_eq!(a1, Deg(500.0));
+        a1 = a1.normalize();
+        assert_ulps_eq!(a1, Deg(140.0));
+        assert_ulps_eq!(a1.normalize(), a1);
+
+        let a2 = Deg(50.0);
+        assert_ulps_eq!(a2 - Deg(150.0), Deg(-100.0));
+        let a3 = a2 - Deg(100.0);
+        assert!(!a3.is_normalized());
+        assert_ulps_eq!(a3.normalize(), Deg(310.0));
+        assert_ulps_eq!(a3.normalize().normalize(), a3.normalize());
+        assert!(a3.normalize().is_normalized());
+
+        let a4 = Rad(consts::PI);
+        let a5 = a4 + Rad(consts::PI * 2.0);
+        assert_ulps_eq!(a5, Rad(consts::PI * 3.0));
+        assert!(!a3.is_normalized());
+        assert_ulps_eq!(a5.normalize(), Rad(consts::PI));
+        let a6 = a4 - Rad(consts::PI * 2.0);
+        assert_ulps_eq!(a6, Rad(-consts::PI));
+        assert!(!a6.is_normalized());
+        assert_ulps_eq!(a6.normalize(), a5.normalize());
+
+        assert_ulps_eq!(Deg(360.0).normalize(), Deg(0.0));
+        assert_ulps_eq!(Deg(-1.0).normalize(), Deg(359.0));
+        assert_ulps_eq!(Deg(-360.0).normalize(), Deg(0.0));
+        assert_relative_eq!(Deg(-359.9).normalize(), Deg(0.1), epsilon=1e-6);
+
+        assert_relative_eq!(Deg(292.5), Gon(725.0).normalize().into_angle(), epsilon=1e-6);
+        assert_relative_eq!(Gon(-275.0).normalize(), Gon(125.0), epsilon=1e-6);
+    }
+
+    #[test]
+    fn decompose() {
+        {
+            let (deg, min, sec) = Deg(50.25).decompose();
+
+            assert_ulps_eq!(deg, Deg(50.0));
+            assert_ulps_eq!(min, ArcMinutes(15.0));
+            assert_ulps_eq!(sec, ArcSeconds(0.0));
+        }
+        {
+            let (deg, min, sec) = Deg(90.3131).decompose();
+
+            assert_ulps_eq!(deg, Deg(90.0));
+            assert_ulps_eq!(min, ArcMinutes(18.0));
+            assert_relative_eq!(sec, ArcSeconds(47.16), epsilon=1e-6);
+        }
+    }
+
+    #[test]
+    fn test_interpolate() {
+        assert_relative_eq!(Deg(60.0).interpolate(&Deg(120.0), 0.5), Deg(90.0));
+        assert_relative_eq!(Deg(50.0).interpolate(&Rad(consts::PI), 0.75), 
+                            Deg(147.5), epsilon=1e-6);
+        assert_relative_eq!(Turns(0.50).interpolate(&Deg(30.0), 0.25), 
+                            Turns(0.39583333333), epsilon=1e-6);
+
+        assert_relative_eq!(Deg(100.0).interpolate(&Deg(310.0), 0.5).normalize(), Deg(25.0));
+        assert_relative_eq!(Deg(100.0).interpolate_forward(&Deg(310.0), 0.5).normalize(), 
+                            Deg(205.0));
+        assert_relative_eq!(Gon(66.6666667).interpolate(&Deg(120.0), 0.5), Gon(100.0), epsilon=1e-6);
+        assert_relative_eq!(Rad::pi_over_2().interpolate(&Rad(0.0), 0.5), Rad::pi_over_4());
+    }
+
+    #[test]
+    fn test_constants() {
+        assert_ulps_eq!(Deg::half_turn(), Deg(180.0));
+        assert_ulps_eq!(Deg::quarter_turn(), Deg(90.0));
+        assert_ulps_eq!(Rad::half_turn(), Rad(consts::PI));
+        assert_ulps_eq!(Rad::<f32>::full_turn(), Rad(Rad::period()));
+        assert_ulps_eq!(Gon::half_turn(), Gon(200.0));
+        assert_ulps_eq!(Gon::quarter_turn(), Gon(100.0));
+    }
+
+    #[test]
+    fn test_invert() {
+        assert_ulps_eq!(Deg(0.0).invert(), Deg(180.0));
+        assert_ulps_eq!(Deg(180.0).invert().normalize(), Deg(0.0));
+        assert_ulps_eq!(Gon(200.0).invert().normalize(), Gon(0.0));
+        assert_ulps_eq!(Deg(80.0).invert(), Deg(260.0));
+        assert_ulps_eq!(Gon(80.0).invert(), Gon(280.0));
+    }
+
+    #[test]
+    fn test_reflect_x() {
+        assert_relative_eq!(Deg(359.9999999999).reflect_x(), 
+            Deg(0.0000000000001), epsilon=1e-5);
+        assert_relative_eq!(Deg(180.0).reflect_x(), Deg(180.0));
+        assert_relative_eq!(Deg(90.0).reflect_x(), Deg(90.0));
+        assert_relative_eq!(Deg(0.0).reflect_x(), Deg(0.0));
+        assert_relative_eq!(Deg(45.0).reflect_x(), Deg(315.0));
+        assert_relative_eq!(Deg(215.0).reflect_x(), Deg(145.0));
+        assert_relative_eq!(Gon(50.0).reflect_x(), Gon(350.0));
+        assert_relative_eq!(Gon(215.0).reflect_x(), Gon(185.0));
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_relative_eq!(mean::<_, _, Deg<_>>(vec![Deg(280.0), Deg(10.0)].into_iter()), Deg(325.0));
+        assert_relative_eq!(mean::<_, _, Deg<_>>(vec![Turns(0.5), Turns(0.0)].into_iter()), Deg(90.0));
+        assert_relative_eq!(mean::<_, _, Rad<_>>([Rad(0.0), Rad(0.0)].iter().cloned()), Rad(0.0));
+    }
+
+    #[test]
+    fn test_times() {
+        assert_ulps_eq!(Deg(72.0).times(5), Deg(360.0));
+        assert_ulps_eq!(Deg(72.0).times(-2), Deg(-144.0));
+        assert_ulps_eq!(Rad(1.5).times(0), Rad(0.0));
+        assert_ulps_eq!(Turns(0.25).times(3), Turns(0.75));
+    }
+
+    #[test]
+    fn test_close_to() {
+        assert!(Deg(90.0).close_to(&Deg(90.0005), 1e-3));
+        assert!(!Deg(90.0).close_to(&Deg(90.0011), 1e-3));
+        assert!(Deg(180.0).close_to(&Rad(consts::PI), 1e-9));
+        assert!(Turns(0.25).close_to(&Deg(90.0), 1e-12));
+        assert!(!Deg(359.9).close_to(&Deg(0.0), 1e-3));
+    }
+
+    #[test]
+    fn test_from_conversions() {
+        let m: ArcMinutes<f64> = Deg(2.0).into();
+        assert_relative_eq!(m, ArcMinutes(120.0), epsilon=1e-9);
+        assert_relative_eq!(ArcSeconds::from(Deg(1.0)), ArcSeconds(3600.0), epsilon=1e-9);
+        assert_relative_eq!(ArcMinutes::from(Gon(100.0)), ArcMinutes(5400.0), epsilon=1e-9);
+        assert_relative_eq!(ArcSeconds::from(Gon(100.0)), ArcSeconds(324000.0), epsilon=1e-6);
+        assert_relative_eq!(ArcMinutes::from(Rad(consts::PI)), ArcMinutes(10800.0), epsilon=1e-9);
+        assert_relative_eq!(ArcSeconds::from(Rad(consts::PI)), ArcSeconds(648000.0), epsilon=1e-6);
+        assert_relative_eq!(ArcMinutes::from(Turns(0.5)), ArcMinutes(10800.0), epsilon=1e-9);
+        assert_relative_eq!(ArcSeconds::from(Turns(0.25)), ArcSeconds(324000.0), epsilon=1e-6);
+        assert_relative_eq!(Gon::from(ArcMinutes(5400.0)), Gon(100.0), epsilon=1e-9);
+        assert_relative_eq!(Rad::from(ArcMinutes(10800.0)), Rad(consts::PI), epsilon=1e-9);
+        assert_relative_eq!(Turns::from(ArcMinutes(5400.0)), Turns(0.25), epsilon=1e-9);
+        assert_relative_eq!(ArcSeconds::from(ArcMinutes(2.0)), ArcSeconds(120.0), epsilon=1e-9);
+        assert_relative_eq!(Gon::from(ArcSeconds(324000.0)), Gon(100.0), epsilon=1e-9);
+        assert_relative_eq!(Rad::from(ArcSeconds(648000.0)), Rad(consts::PI), epsilon=1e-9);
+        assert_relative_eq!(Turns::from(ArcSeconds(648000.0)), Turns(0.5), epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_format_dms() {
+        let angle = Deg(50.0) + ArcMinutes(30.0) + ArcSeconds(10.5);
+        assert_eq!(angle.format_dms(":", ":", "", 2), "50:30:10.50");
+        assert_eq!(angle.format_dms("° ", "' ", "\"", 1), "50° 30' 10.5\"");
+        assert_eq!(Deg(-12.5).format_dms("°", "'", "\"", 0), "-12°30'00\"");
+        assert_eq!(Deg(10.999999).format_dms(" ", " ", "", 1), "11 00 00.0");
+        assert_eq!(Deg(-1e-9).format_dms("°", "'", "\"", 0), "0°00'00\"");
+        assert_eq!(Deg(-1e-9).format_dms("°", "'", "\"", 3), "0°00'00.000\"");
+        assert_eq!(Deg(-0.001).format_dms("°", "'", "\"", 1), "-0°00'03.6\"");
+    }
+
+    #[test]
+    fn test_angular_extent() {
+        let (start, end) = angular_extent(&[Deg(350.0), Deg(10.0), Deg(5.0), Deg(-5.0)]).unwrap();
+        assert_ulps_eq!(start, Deg(350.0));
+        assert_ulps_eq!(end, Deg(10.0));
+
+        let (start, end) = angular_extent(&[Deg(100.0), Deg(40.0), Deg(70.0)]).unwrap();
+        assert_ulps_eq!(start, Deg(40.0));
+        assert_ulps_eq!(end, Deg(100.0));
+
+        let (start, end) = angular_extent(&[Turns(0.3)]).unwrap();
+        assert_ulps_eq!(start, Turns(0.3));
+        assert_ulps_eq!(end, Turns(0.3));
+
+        assert!(angular_extent::<Deg<f64>>(&[]).is_none());
+    }
+
+    #[test]
+    fn test_interpolate_quantized() {
+        assert_ulps_eq!(Deg(0.0).interpolate_quantized(&Deg(90.0), 0.4, 4), Deg(0.0));
+        assert_ulps_eq!(Deg(0.0).interpolate_quantized(&Deg(90.0), 0.6, 4), Deg(90.0));
+        assert_ulps_eq!(Deg(90.0).interpolate_quantized(&Deg(270.0), 0.6, 4), Deg(180.0));
+        assert_ulps_eq!(Deg(300.0).interpolate_quantized(&Deg(20.0), 0.9, 4), Deg(0.0));
+        assert_ulps_eq!(Deg(300.0).interpolate_quantized(&Deg(20.0), 0.1, 4), Deg(270.0));
+        assert_relative_eq!(Rad(0.0).interpolate_quantized(&Deg(100.0), 0.5, 8),
+                            Rad::pi_over_4(), epsilon=1e-9);
+        assert_ulps_eq!(Deg(10.0).interpolate_quantized(&Deg(20.0), 0.5, 0), Deg(15.0));
+    }
+
+    #[test]
+    fn test_cumulative() {
+        let totals = cumulative(&[Deg(90.0), Deg(180.0), Deg(-45.0), Deg(200.0)]);
+        assert_eq!(totals, vec![Deg(90.0), Deg(270.0), Deg(225.0), Deg(425.0)]);
+        assert!(cumulative::<Rad<f64>>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_negate_normalized() {
+        assert_ulps_eq!(Deg(30.0).negate_normalized(), Deg(330.0));
+        assert_ulps_eq!(Deg(-30.0).negate_normalized(), Deg(30.0));
+        assert_ulps_eq!(Deg(400.0).negate_normalized(), Deg(320.0));
+        assert_ulps_eq!(Turns(0.0).negate_normalized(), Turns(0.0));
+        for angle in &[Deg(30.0), Deg(-30.0), Deg(400.0), Deg(-725.0), Deg(0.0)] {
+            assert!(angle.negate_normalized().is_normalized());
+        }
+    }
+
+    #[test]
+    fn test_wrapped() {
+        assert_ulps_eq!(Deg(-90.0).wrapped_unsigned(), Deg(270.0));
+        assert_ulps_eq!(Deg(-90.0).wrapped_signed(), Deg(-90.0));
+        assert_ulps_eq!(Deg(270.0).wrapped_unsigned(), Deg(270.0));
+        assert_ulps_eq!(Deg(270.0).wrapped_signed(), Deg(-90.0));
+        assert_ulps_eq!(Deg(540.0).wrapped_unsigned(), Deg(180.0));
+        assert_ulps_eq!(Deg(540.0).wrapped_signed(), Deg(-180.0));
+        assert_ulps_eq!(Deg(179.0).wrapped_signed(), Deg(179.0));
+        assert_relative_eq!(Rad(consts::PI * 1.5).wrapped_signed(), Rad(-consts::PI / 2.0));
+    }
+
+    #[test]
+    fn test_checked_add_sub() {
+        assert_eq!(Deg(10.0).checked_add(Deg(20.0)), Some(Deg(30.0)));
+        assert_eq!(Deg(10.0).checked_sub(Deg(20.0)), Some(Deg(-10.0)));
+        assert_relative_eq!(Deg(10.0).checked_add(Turns(0.25)).unwrap(), Deg(100.0));
+        assert_eq!(Deg(10.0).checked_add(Deg(f64::INFINITY)), None);
+        assert_eq!(Deg(10.0).checked_sub(Deg(f64::NEG_INFINITY)), None);
+        assert_eq!(Deg(f64::NAN).checked_add(Deg(1.0)), None);
+        assert_eq!(Deg(f64::MAX).checked_add(Deg(f64::MAX)), None);
+    }
+
+    #[test]
+    fn test_revolutions() {
+        assert_relative_eq!(Deg::from(Revolutions(0.25)), Deg(90.0));
+        assert_relative_eq!(Revolutions::from(Rad(consts::PI)), Revolutions(0.5));
+        assert_relative_eq!(Revolutions(0.25) + Deg(90.0), Revolutions(0.5));
+        assert_eq!(format!("{}", Revolutions(1.5)), "1.5rev");
+
+        assert_relative_eq!(rpm_to_angle_per_second::<Deg<f64>>(60.0), Deg(360.0));
+        assert_relative_eq!(rpm_to_angle_per_second::<Revolutions<f64>>(90.0), Revolutions(1.5));
+        assert_relative_eq!(rpm_to_angle_per_second::<Rad<f64>>(30.0), Rad(consts::PI));
+    }
+
+    #[test]
+    fn test_partial_cmp_normalized() {
+        assert_eq!(Deg(370.0).partial_cmp_normalized(&Deg(10.0)), Some(Ordering::Equal));
+        assert_eq!(Deg(-10.0).partial_cmp_normalized(&Deg(20.0)), Some(Ordering::Greater));
+        assert_eq!(Deg(30.0).partial_cmp_normalized(&Deg(380.0)), Some(Ordering::Greater));
+        assert_eq!(Deg(5.0).partial_cmp_normalized(&Turns(0.5)), Some(Ordering::Less));
+        assert_eq!(Deg(f64::NAN).partial_cmp_normalized(&Deg(10.0)), None);
+        assert_eq!(Deg(10.0).partial_cmp_normalized(&Deg(f64::NAN)), None);
+    }
+
+    #[test]
+    fn test_blend() {
+        assert_relative_eq!(Deg(350.0).blend(&Deg(10.0), 0.5), Deg(0.0));
+        assert_relative_eq!(Deg(350.0).blend(&Deg(10.0), 0.75), Deg(5.0));
+        assert_relative_eq!(Deg(350.0).blend(&Deg(10.0), 0.25), Deg(355.0));
+        assert_relative_eq!(Deg(10.0).blend(&Deg(350.0), 0.75), Deg(355.0));
+        assert_relative_eq!(Deg(10.0).blend(&Deg(350.0), 1.0), Deg(350.0));
+        assert!(Deg(350.0).blend(&Deg(10.0), 0.5).is_normalized());
+    }
+
+    #[test]
+    fn test_from_radians_degrees() {
+        assert_relative_eq!(Turns::from_degrees(90.0), Turns(0.25));
+        assert_relative_eq!(Turns::from_radians(consts::PI), Turns(0.5));
+        assert_relative_eq!(Deg::from_degrees(45.0), Deg(45.0));
+        assert_relative_eq!(Deg::from_radians(consts::PI), Deg(180.0));
+        assert_relative_eq!(Rad::from_degrees(180.0), Rad(consts::PI));
+        assert_relative_eq!(Rad::from_radians(1.0), Rad(1.0));
+        assert_relative_eq!(Gon::from_degrees(90.0), Gon(100.0));
+        assert_relative_eq!(Gon::from_radians(consts::PI), Gon(200.0));
+        assert_relative_eq!(ArcMinutes::from_degrees(1.0), ArcMinutes(60.0));
+        assert_relative_eq!(ArcMinutes::from_radians(consts::PI), ArcMinutes(10800.0));
+        assert_relative_eq!(ArcSeconds::from_degrees(1.0), ArcSeconds(3600.0));
+        assert_relative_eq!(ArcSeconds::from_radians(consts::PI), ArcSeconds(648000.0));
+        assert_relative_eq!(Revolutions::from_degrees(90.0), Revolutions(0.25));
+        assert_relative_eq!(Revolutions::from_radians(consts::PI), Revolutions(0.5));
+    }
+
+    #[test]
+    fn test_interpolate_turns() {
+        assert_relative_eq!(Deg(0.0).interpolate_turns(&Deg(90.0), 0.0, 2), Deg(0.0));
+        assert_relative_eq!(Deg(0.0).interpolate_turns(&Deg(90.0), 1.0, 2), Deg(810.0));
+        assert_relative_eq!(Deg(0.0).interpolate_turns(&Deg(90.0), 0.5, 2), Deg(405.0));
+        assert_relative_eq!(Deg(0.0).interpolate_turns(&Deg(90.0), 1.0, 0), Deg(90.0));
+        assert_relative_eq!(Deg(0.0).interpolate_turns(&Deg(90.0), 1.0, -1), Deg(-630.0));
+        assert_relative_eq!(Deg(0.0).interpolate_turns(&Deg(90.0), 0.5, -1), Deg(-315.0));
+        assert_relative_eq!(Deg(350.0).interpolate_turns(&Deg(10.0), 1.0, 1).normalize(), Deg(10.0));
+        assert_relative_eq!(Turns(0.0).interpolate_turns(&Deg(180.0), 0.5, 1), Turns(0.75));
+    }
+
+    #[test]
+    fn test_fixed_bits() {
+        assert_eq!(Deg(90.0).to_fixed_bits(16), Some(0x4000));
+        assert_eq!(Deg(-90.0).to_fixed_bits(16), Some(0xC000));
+        assert_eq!(Turns(0.5).to_fixed_bits(8), Some(0x80));
+        assert_eq!(Deg(360.0).to_fixed_bits(16), Some(0));
+        assert_eq!(Deg(359.9999).to_fixed_bits(8), Some(0));
+
+        for &bits in &[8, 12, 16, 32] {
+            let angle = Deg(123.0);
+            let tolerance = 360.0 / 2f64.powi(bits as i32);
+            let fixed = angle.to_fixed_bits(bits).unwrap();
+            assert!(Deg::from_fixed_bits(fixed, bits).close_to(&angle, tolerance));
+        }
+        assert_relative_eq!(Deg::from_fixed_bits(0x4000, 16), Deg(90.0));
+        assert_relative_eq!(Rad::from_fixed_bits(0x8000_0000, 32), Rad(consts::PI));
+    }
+
+    #[test]
+    fn test_fixed_bits_out_of_range() {
+        assert_eq!(Deg(f64::NAN).to_fixed_bits(16), None);
+        assert_eq!(Deg(f64::INFINITY).to_fixed_bits(16), None);
+        assert_eq!(Deg(180.0).to_fixed_bits(70), None);
+        assert_eq!(Deg(180.0).to_fixed_bits(64), Some(1 << 63));
+        assert_eq!(Turns(0.25_f32).to_fixed_bits(64), Some(1 << 62));
+    }
+
+    #[test]
+    fn test_sum_normalized() {
+        let angles = vec![Deg(170.0); 10];
+        assert_relative_eq!(sum_normalized(angles), Deg(260.0), epsilon=1e-9);
+        assert_relative_eq!(sum_normalized(vec![Deg(-30.0), Deg(-40.0)]), Deg(290.0));
+        assert_relative_eq!(sum_normalized(Vec::<Rad<f64>>::new()), Rad(0.0));
+
+        let rotations = [Deg(90.0), Deg(-45.0), Deg(270.0), Deg(100.0)];
+        assert_relative_eq!(sum_normalized(rotations.iter().cloned()), Deg(55.0));
+        assert_relative_eq!(sum_normalized(rotations.iter().rev().cloned()), Deg(55.0));
+        assert_relative_eq!(compose_rotations(rotations.iter().cloned()), Deg(55.0));
+        assert_relative_eq!(compose_rotations(Vec::<Deg<f64>>::new()), Deg(0.0));
+    }
+
+    #[test]
+    fn test_wrap_approx() {
+        assert_relative_eq!(WrapApprox(Turns(0.9999999)), WrapApprox(Turns(0.0)), epsilon=1e-5);
+        assert_relative_eq!(WrapApprox(Turns(0.0)), WrapApprox(Turns(0.9999999)), epsilon=1e-5);
+        assert_relative_eq!(WrapApprox(Deg(359.9999)), WrapApprox(Deg(0.0001)), epsilon=1e-3);
+        assert_relative_eq!(WrapApprox(Rad(consts::PI * 2.0 - 1e-9)), WrapApprox(Rad(0.0)),
+                            epsilon=1e-6);
+        assert_relative_eq!(WrapApprox(Gon(399.9999)), WrapApprox(Gon(0.0)), epsilon=1e-3);
+        assert_relative_eq!(WrapApprox(ArcMinutes(21599.99)), WrapApprox(ArcMinutes(0.0)),
+                            epsilon=1e-1);
+        assert_relative_eq!(WrapApprox(ArcSeconds(-0.5)), WrapApprox(ArcSeconds(1295999.6)),
+                            epsilon=1.0);
+        assert_ulps_eq!(WrapApprox(Deg(720.0)), WrapApprox(Deg(0.0)));
+        assert_abs_diff_eq!(WrapApprox(Deg(-0.001)), WrapApprox(Deg(359.999)), epsilon=1e-6);
+        assert!(relative_ne!(WrapApprox(Deg(10.0)), WrapApprox(Deg(350.0)), epsilon=1e-3));
+    }
+
+    #[test]
+    fn test_parse_any_angle() {
+        assert_eq!(parse_any_angle("90°"), Ok(TaggedAngle::Deg(Deg(90.0))));
+        assert_eq!(parse_any_angle("1.5r"), Ok(TaggedAngle::Rad(Rad(1.5))));
+        assert_eq!(parse_any_angle("100g"), Ok(TaggedAngle::Gon(Gon(100.0))));
+        assert_eq!(parse_any_angle("100gon"), Ok(TaggedAngle::Gon(Gon(100.0))));
+        assert_eq!(parse_any_angle("30'"), Ok(TaggedAngle::ArcMinutes(ArcMinutes(30.0))));
+        assert_eq!(parse_any_angle("-15.5\""), Ok(TaggedAngle::ArcSeconds(ArcSeconds(-15.5))));
+        assert_eq!(parse_any_angle("2rev"), Ok(TaggedAngle::Revolutions(Revolutions(2.0))));
+        assert_eq!(parse_any_angle(" 0.25 "), Ok(TaggedAngle::Turns(Turns(0.25))));
+        assert_eq!(parse_any_angle(&format!("{}", Deg(45.0))), Ok(TaggedAngle::Deg(Deg(45.0))));
+
+        assert!(parse_any_angle("").is_err());
+        assert!(parse_any_angle("abc°").is_err());
+        assert!(parse_any_angle("12x").is_err());
+
+        let angle: Deg<f64> = parse_any_angle("0.5").unwrap().to_angle();
+        assert_relative_eq!(angle, Deg(180.0));
+        let angle: Deg<f64> = parse_any_angle("90°").unwrap().to_angle();
+        assert_eq!(angle, Deg(90.0));
+        let angle: ArcMinutes<f64> = parse_any_angle("0.5°").unwrap().to_angle();
+        assert_eq!(angle, ArcMinutes(30.0));
+        let angle: Turns<f64> = parse_any_angle("100g").unwrap().to_angle();
+        assert_eq!(angle, Turns(0.25));
+    }
+
+    #[test]
+    fn test_interpolate_clamped() {
+        assert_relative_eq!(Deg(10.0).interpolate_clamped(&Deg(50.0), 1.5), Deg(50.0));
+        assert_relative_eq!(Deg(10.0).interpolate(&Deg(50.0), 1.5), Deg(70.0));
+        assert_relative_eq!(Deg(10.0).interpolate_clamped(&Deg(50.0), -0.5), Deg(10.0));
+        assert_relative_eq!(Deg(10.0).interpolate(&Deg(50.0), -0.5), Deg(-10.0));
+        assert_relative_eq!(Deg(10.0).interpolate_clamped(&Deg(50.0), 0.25), Deg(20.0));
+        assert_relative_eq!(Deg(350.0).interpolate_clamped(&Deg(10.0), 2.0).normalize(), Deg(10.0));
+    }
+
+    #[test]
+    fn test_exact_conversions() {
+        assert_eq!(ArcSeconds::from_angle(Deg(1.0)), ArcSeconds(3600.0));
+        assert_eq!(ArcMinutes::from_angle(Deg(1.0)), ArcMinutes(60.0));
+        assert_eq!(ArcSeconds::from_angle(ArcMinutes(1.0)), ArcSeconds(60.0));
+        assert_eq!(Deg::from_angle(ArcSeconds(3600.0)), Deg(1.0));
+        assert_eq!(Deg::from_angle(ArcMinutes(90.0)), Deg(1.5));
+
+        for &value in &[1.0, 7.0, 45.0, 90.0, 359.0, -123.0] {
+            let secs: ArcSeconds<f64> = Deg(value).into_angle();
+            assert_eq!(Deg::from_angle(secs), Deg(value));
+            let mins: ArcMinutes<f64> = Deg(value).into_angle();
+            assert_eq!(Deg::from_angle(mins), Deg(value));
+            let secs: ArcSeconds<f64> = mins.into_angle();
+            assert_eq!(ArcMinutes::from_angle(secs), mins);
+
+            let secs: ArcSeconds<f32> = Deg(value as f32).into_angle();
+            assert_eq!(Deg::from_angle(secs), Deg(value as f32));
+        }
+    }
+
+    #[test]
+    fn test_delta() {
+        assert_relative_eq!(Deg(10.0).delta(&Deg(350.0)), Deg(20.0));
+        assert_relative_eq!(Deg(10.0) - Deg(350.0), Deg(-340.0));
+        assert_relative_eq!(Deg(350.0).delta(&Deg(10.0)), Deg(-20.0));
+        assert_relative_eq!(Deg(350.0) - Deg(10.0), Deg(340.0));
+        assert_relative_eq!(Deg(90.0).delta(&Deg(30.0)), Deg(60.0));
+        assert_relative_eq!(Deg(0.0).delta(&Deg(180.0)), Deg(-180.0));
+        assert_relative_eq!(Deg(725.0).delta(&Rad(0.0)), Deg(5.0));
+    }
+
+    #[test]
+    fn test_within() {
+        assert!(Deg(5.0).within(Deg(355.0), Deg(15.0)));
+        assert!(Deg(355.0).within(Deg(5.0), Deg(15.0)));
+        assert!(Deg(5.0).within(Deg(355.0), Deg(10.0)));
+        assert!(!Deg(5.0).within(Deg(355.0), Deg(9.0)));
+        assert!(Deg(100.0).within(Deg(90.0), Deg(10.0)));
+        assert!(!Deg(100.0).within(Deg(80.0), Deg(10.0)));
+        assert!(Deg(1.0).within(Turns(0.0), Deg(2.0)));
+    }
+
+    #[test]
+    fn test_bare() {
+        assert_eq!(format!("{}", Deg(1.5).bare()), "1.5");
+        assert_eq!(format!("{:.3}", Deg(1.2345).bare()), "1.234");
+        assert_eq!(format!("{:.1}", Rad(2.0).bare()), "2.0");
+        assert_eq!(format!("{:>6}", Gon(50.0).bare()), "    50");
+    }
+
+    #[test]
+    fn test_normalize_boundaries() {
+        assert_eq!(Deg(360.0e9 + 45.0).normalize(), Deg(45.0));
+        assert_eq!(Deg(-360.0e9 + 45.0).normalize(), Deg(45.0));
+        assert_eq!(Deg(1.0e12 + 45.0).normalize(), Deg(325.0));
+        assert_eq!(Deg(720.0).normalize(), Deg(0.0));
+        assert_eq!(Deg(-720.0).normalize(), Deg(0.0));
+        assert_eq!(Turns(-1.0e-20).normalize(), Turns(0.0));
+        assert!(Deg(-1.0e-14).normalize().is_normalized());
+        assert!(Rad(-1.0e-17).normalize().is_normalized());
+        assert!(Deg(-1.0e-6f32).normalize().is_normalized());
+    }
+
+    #[test]
+    fn test_default_epsilon_scaling() {
+        let close = Rad(0.5 * f64::EPSILON);
+        let far = Rad(2.0 * f64::EPSILON);
+
+        assert_abs_diff_eq!(Rad(0.0), close);
+        assert_abs_diff_eq!(ArcSeconds(0.0), ArcSeconds::from_angle(close));
+        assert_abs_diff_eq!(Turns(0.0), Turns::from_angle(close));
+
+        assert_abs_diff_ne!(Rad(0.0), far);
+        assert_abs_diff_ne!(ArcSeconds(0.0), ArcSeconds::from_angle(far));
+        assert_abs_diff_ne!(Turns(0.0), Turns::from_angle(far));
+    }
+
+    #[test]
+    fn test_clamp_to_fov() {
+        assert_eq!(Deg(10.0).clamp_to_fov(Deg(300.0), Deg(60.0)), (Deg(10.0), false));
+        assert_eq!(Deg(330.0).clamp_to_fov(Deg(-60.0), Deg(60.0)), (Deg(330.0), false));
+        assert_eq!(Deg(280.0).clamp_to_fov(Deg(300.0), Deg(60.0)), (Deg(300.0), true));
+        assert_eq!(Deg(80.0).clamp_to_fov(Deg(300.0), Deg(60.0)), (Deg(60.0), true));
+        assert_eq!(Deg(-100.0).clamp_to_fov(Deg(300.0), Deg(60.0)), (Deg(300.0), true));
+        assert_eq!(Deg(50.0).clamp_to_fov(Deg(60.0), Deg(120.0)), (Deg(60.0), true));
+    }
+
+    #[cfg(feature = "euclid")]
+    #[test]
+    fn test_euclid() {
+        let angle: euclid::Angle<f64> = Deg(90.0).into();
+        assert_relative_eq!(angle.radians, consts::PI / 2.0);
+        assert_relative_eq!(Deg::from(angle), Deg(90.0));
+
+        let angle: euclid::Angle<f64> = Rad(1.25).into();
+        assert_eq!(angle.radians, 1.25);
+        assert_eq!(Rad::from(angle), Rad(1.25));
+
+        assert_relative_eq!(Turns::from(euclid::Angle::pi()), Turns(0.5));
+    }
+
+    #[cfg(feature = "nalgebra")]
+    #[test]
+    fn test_nalgebra() {
+        let rotated = Deg(90.0).to_rotation2() * nalgebra::Vector2::new(1.0, 0.0);
+        assert_relative_eq!(rotated.x, 0.0, epsilon=1e-12);
+        assert_relative_eq!(rotated.y, 1.0, epsilon=1e-12);
+
+        let rotated = Turns(0.5).to_unit_complex() * nalgebra::Vector2::new(1.0, 0.0);
+        assert_relative_eq!(rotated.x, -1.0, epsilon=1e-12);
+        assert_relative_eq!(rotated.y, 0.0, epsilon=1e-12);
+
+        assert_relative_eq!(Rad::from(Deg(30.0).to_rotation2()), Rad(consts::PI / 6.0));
+        assert_relative_eq!(Rad::from(nalgebra::UnitComplex::new(1.0)), Rad(1.0));
+    }
+
+    #[test]
+    fn test_fold_symmetry() {
+        assert_relative_eq!(Deg(130.0).fold_symmetry(6), Deg(10.0), epsilon=1e-9);
+        assert_relative_eq!(Deg(-50.0).fold_symmetry(6), Deg(10.0), epsilon=1e-9);
+        assert_relative_eq!(Deg(200.0).fold_symmetry(2), Deg(20.0), epsilon=1e-9);
+        assert_relative_eq!(Deg(275.0).fold_symmetry(4), Deg(5.0), epsilon=1e-9);
+        assert_relative_eq!(Deg(90.0).fold_symmetry(4), Deg(0.0), epsilon=1e-9);
+        assert_relative_eq!(Deg(370.0).fold_symmetry(0), Deg(10.0), epsilon=1e-9);
+        assert_relative_eq!(Deg(370.0).fold_symmetry(1), Deg(10.0), epsilon=1e-9);
+    }
+
+    #[test]
+    fn test_assert_angle_eq() {
+        assert_angle_eq!(Deg(359.999), Deg(0.0), epsilon = 1e-2);
+        assert_angle_eq!(Deg(0.001), Deg(720.0), epsilon = 1e-2);
+        assert_angle_eq!(Rad(consts::PI), Deg(180.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    #[should_panic(expected = "difference = 10")]
+    fn test_assert_angle_eq_fails() {
+        assert_angle_eq!(Deg(10.0), Deg(360.0), epsilon = 1e-2);
+    }
+
+    #[test]
+    fn test_compass_abbr() {
+        assert_eq!(Deg(0.0).to_compass_abbr(CompassResolution::Sixteen), Some("N"));
+        assert_eq!(Deg(22.5).to_compass_abbr(CompassResolution::Sixteen), Some("NNE"));
+        assert_eq!(Deg(355.0).to_compass_abbr(CompassResolution::Sixteen), Some("N"));
+        assert_eq!(Deg(100.0).to_compass_abbr(CompassResolution::Four), Some("E"));
+        assert_eq!(Deg(-40.0).to_compass_abbr(CompassResolution::Eight), Some("NW"));
+        assert_eq!(Turns(0.5).to_compass_abbr(CompassResolution::Eight), Some("S"));
+        assert_eq!(Deg(f64::NAN).to_compass_abbr(CompassResolution::Sixteen), None);
+        assert_eq!(Deg(f64::INFINITY).to_compass_abbr(CompassResolution::Four), None);
+
+        assert_relative_eq!(Deg::from_compass_abbr("NNE").unwrap(), Deg(22.5));
+        assert_relative_eq!(Deg::from_compass_abbr("sw").unwrap(), Deg(225.0));
+        assert_relative_eq!(Turns::from_compass_abbr("E").unwrap(), Turns(0.25));
+        assert_eq!(Deg::<f64>::from_compass_abbr("NNN"), Err(ParseAngleError::InvalidCompassPoint));
+
+        for &abbr in &["N", "ENE", "SSW", "NW"] {
+            let bearing = Deg::<f64>::from_compass_abbr(abbr).unwrap();
+            assert_eq!(bearing.to_compass_abbr(CompassResolution::Sixteen), Some(abbr));
+        }
+    }
+
+    #[test]
+    fn test_integrate_trapezoid() {
+        assert_relative_eq!(integrate_trapezoid(&[Deg(10.0); 5], 0.5), Deg(20.0));
+        assert_relative_eq!(integrate_trapezoid(&[Deg(0.0), Deg(10.0), Deg(20.0)], 1.0), Deg(20.0));
+        assert_relative_eq!(integrate_trapezoid(&[Rad(2.0); 101], 0.1), Rad(20.0), epsilon=1e-9);
+        assert_eq!(integrate_trapezoid(&[Deg(10.0)], 1.0), Deg(0.0));
+        assert_eq!(integrate_trapezoid::<Deg<f64>>(&[], 1.0), Deg(0.0));
+    }
+
+    #[test]
+    fn test_wrap_near() {
+        assert_relative_eq!(Deg(10.0).wrap_near(Deg(350.0)), Deg(370.0));
+        assert_relative_eq!(Deg(350.0).wrap_near(Deg(10.0)), Deg(-10.0));
+        assert_relative_eq!(Deg(350.0).wrap_near(Deg(0.0)), Deg(-10.0));
+        assert_relative_eq!(Deg(5.0).wrap_near(Deg(355.0)), Deg(365.0));
+        assert_relative_eq!(Deg(90.0).wrap_near(Deg(100.0)), Deg(90.0));
+        assert_relative_eq!(Deg(-270.0).wrap_near(Turns(0.0)), Deg(90.0));
+
+        assert_relative_eq!(Deg(10.0).wrap_near(Deg(720.0)), Deg(730.0));
+        assert_relative_eq!(Deg(10.0).align_to(Deg(720.0)), Deg(730.0));
+        assert_relative_eq!(Deg(350.0).align_to(Deg(-1080.0)), Deg(-1090.0));
+        assert_relative_eq!(Deg(10.0).wrap_near(Deg(1790.0)), Deg(1810.0));
+        assert_relative_eq!(Deg(350.0).wrap_near(Deg(-1080.0)), Deg(-1090.0));
+        assert_relative_eq!(Deg(3610.0).wrap_near(Deg(0.0)), Deg(10.0), epsilon = 1e-9);
+
+        assert_relative_eq!(Deg(10.0).wrap_near(Deg(180.0)), Deg(10.0));
+        assert_relative_eq!(Deg(-10.0).wrap_near(Deg(180.0)), Deg(350.0));
+        assert_relative_eq!(Deg(0.0).wrap_near(Deg(180.0)), Deg(0.0));
+        assert_relative_eq!(Deg(360.0).wrap_near(Deg(180.0)), Deg(0.0));
+        assert_relative_eq!(Deg(-10.0).wrap_near(Deg(-90.0)), Deg(-10.0));
+        for &angle in &[10.0, 170.0, 180.0, 200.0, -400.0] {
+            assert_relative_eq!(Deg(angle).wrap_near(Deg(0.0)), Deg(angle).wrapped_signed(),
+                                epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_min_max_ignore_nan() {
+        assert_eq!(Deg(10.0).min_ignore_nan(Deg(20.0)), Deg(10.0));
+        assert_eq!(Deg(10.0).max_ignore_nan(Deg(20.0)), Deg(20.0));
+        assert_eq!(Deg(f64::NAN).min_ignore_nan(Deg(20.0)), Deg(20.0));
+        assert_eq!(Deg(10.0).min_ignore_nan(Deg(f64::NAN)), Deg(10.0));
+        assert_eq!(Rad(f64::NAN).max_ignore_nan(Rad(1.0)), Rad(1.0));
+        assert_eq!(Rad(1.0).max_ignore_nan(Rad(f64::NAN)), Rad(1.0));
+    }
+
+    #[test]
+    fn test_abs_diff() {
+        assert_relative_eq!(Deg(10.0).abs_diff(&Deg(350.0)), Deg(20.0));
+        assert_relative_eq!(Deg(350.0).abs_diff(&Deg(10.0)), Deg(20.0));
+        assert_relative_eq!(Deg(0.0).abs_diff(&Deg(180.0)), Deg(180.0));
+        assert_relative_eq!(Deg(180.0).abs_diff(&Deg(0.0)), Deg(180.0));
+        assert_relative_eq!(Deg(30.0).abs_diff(&Rad(0.0)), Deg(30.0));
+
+        for &(a, b) in &[(5.0, 355.0), (100.0, 260.0), (-30.0, 725.0), (90.0, 90.0)] {
+            assert_relative_eq!(Deg(a).abs_diff(&Deg(b)), Deg(b).abs_diff(&Deg(a)));
+            assert!(Deg(a).abs_diff(&Deg(b)) >= Deg(0.0));
+        }
+    }
+
+    #[test]
+    fn test_decompose_all() {
+        let parts = decompose_all(&[Deg(50.25), Deg(90.5), Deg(10.0)]);
+        assert_eq!(parts.len(), 3);
+        assert_eq!(parts[0], (Deg(50.0), ArcMinutes(15.0), ArcSeconds(0.0)));
+        assert_eq!(parts[1], (Deg(90.0), ArcMinutes(30.0), ArcSeconds(0.0)));
+        assert_eq!(parts[2], (Deg(10.0), ArcMinutes(0.0), ArcSeconds(0.0)));
+        assert!(decompose_all::<f64>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_decompose_normalized() {
+        assert_eq!(Deg(-10.0).decompose_normalized(), (Deg(350.0), ArcMinutes(0.0), ArcSeconds(0.0)));
+        assert_eq!(Deg(-10.0).decompose().0, Deg(-10.0));
+        assert_eq!(Deg(370.5).decompose_normalized(), (Deg(10.0), ArcMinutes(30.0), ArcSeconds(0.0)));
+        assert_eq!(Deg(45.25).decompose_normalized(), Deg(45.25).decompose());
+    }
+
+    #[test]
+    fn test_try_interpolate() {
+        assert_eq!(Deg(10.0).try_interpolate(&Deg(50.0), f64::NAN), None);
+        assert_eq!(Deg(10.0).try_interpolate(&Deg(50.0), f64::INFINITY), None);
+        assert_eq!(Deg(10.0).try_interpolate(&Deg(50.0), f64::NEG_INFINITY), None);
+        assert_relative_eq!(Deg(10.0).try_interpolate(&Deg(50.0), 0.5).unwrap(), Deg(30.0));
+        assert!(Deg(10.0).interpolate(&Deg(50.0), f64::NAN).0.is_nan());
+    }
+
+    #[test]
+    fn test_turn_direction() {
+        assert_eq!(Deg(10.0).turn_direction(&Deg(50.0)), Ordering::Greater);
+        assert_eq!(Deg(350.0).turn_direction(&Deg(10.0)), Ordering::Greater);
+        assert_eq!(Deg(50.0).turn_direction(&Deg(10.0)), Ordering::Less);
+        assert_eq!(Deg(10.0).turn_direction(&Deg(350.0)), Ordering::Less);
+        assert_eq!(Deg(10.0).turn_direction(&Deg(190.0)), Ordering::Equal);
+        assert_eq!(Deg(10.0).turn_direction(&Deg(370.0)), Ordering::Equal);
+        assert_eq!(Rad(0.0).turn_direction(&Rad(consts::PI)), Ordering::Equal);
+    }
+
+    #[test]
+    fn test_wrap_all() {
+        let wrapped = wrap_all(&[Deg(-30.0), Deg(350.0), Deg(730.0), Deg(1085.0)]);
+        assert_eq!(wrapped, vec![Deg(330.0), Deg(350.0), Deg(10.0), Deg(5.0)]);
+        assert!(wrap_all::<Rad<f64>>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_from_sin_cos() {
+        let half_sqrt2 = f64::sqrt(2.0) / 2.0;
+        assert_relative_eq!(Deg::from_sin_cos(1.0, 0.0), Deg(90.0));
+        assert_relative_eq!(Deg::from_sin_cos(-1.0, 0.0), Deg(270.0));
+        assert_relative_eq!(Deg::from_sin_cos(half_sqrt2, half_sqrt2), Deg(45.0));
+        assert_relative_eq!(Deg::from_sin_cos(half_sqrt2, -half_sqrt2), Deg(135.0));
+        assert_relative_eq!(Deg::from_sin_cos(-half_sqrt2, -half_sqrt2), Deg(225.0));
+        assert_relative_eq!(Deg::from_sin_cos(-half_sqrt2, half_sqrt2), Deg(315.0));
+        let (sin, cos) = Deg(200.0).sin_cos();
+        assert_relative_eq!(Turns::from_sin_cos(sin, cos), Turns::from_degrees(200.0), epsilon=1e-12);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_reference_arithmetic() {
+        let (a, b) = (Deg(30.0), Deg(45.0));
+        assert_eq!(&a + &b, Deg(75.0));
+        assert_eq!(&a - &b, Deg(-15.0));
+        assert_eq!(&a * &2.0, Deg(60.0));
+        assert_eq!(&a / &2.0, Deg(15.0));
+        assert_eq!(-&a, Deg(-30.0));
+        assert_relative_eq!(&a + &Turns(0.25), Deg(120.0));
+
+        let (c, d) = (Rad(1.0f32), Rad(0.5f32));
+        assert_eq!(&c + &d, Rad(1.5));
+        assert_eq!(&c - &d, Rad(0.5));
+        assert_eq!(&c * &3.0, Rad(3.0));
+        assert_eq!(a, Deg(30.0));
+    }
+
+    #[test]
+    fn test_normalize_into() {
+        assert_relative_eq!(Rad(3.0 * consts::PI).normalize_into::<Deg<f64>>(), Deg(180.0));
+        assert_relative_eq!(Deg(-90.0).normalize_into::<Turns<f64>>(), Turns(0.75));
+        assert_relative_eq!(Turns(2.5).normalize_into::<Gon<f64>>(), Gon(200.0));
+        assert_relative_eq!(Deg(45.0).normalize_into::<Rad<f64>>(), Rad::pi_over_4());
+    }
+
+    #[test]
+    fn test_scale_about() {
+        assert_relative_eq!(Deg(100.0).scale_about(Deg(90.0), 0.0), Deg(90.0));
+        assert_relative_eq!(Deg(100.0).scale_about(Deg(90.0), 1.0), Deg(100.0));
+        assert_relative_eq!(Deg(100.0).scale_about(Deg(90.0), 2.0), Deg(110.0));
+        assert_relative_eq!(Deg(350.0).scale_about(Deg(10.0), 2.0), Deg(-30.0));
+        assert_relative_eq!(Deg(20.0).scale_about(Deg(350.0), 0.5), Deg(365.0));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_dms_repr() {
+        use self::serde_test::{Token, assert_tokens};
+
+        let dms = DmsRepr::from(Deg(50.25));
+        assert_eq!(dms, DmsRepr {degrees: 50, minutes: 15, seconds: 0.0, negative: false});
+        assert_relative_eq!(Deg::from(dms), Deg(50.25));
+
+        let dms = DmsRepr::from(Deg(-0.5));
+        assert_eq!(dms, DmsRepr {degrees: 0, minutes: 30, seconds: 0.0, negative: true});
+        assert_relative_eq!(Deg::from(dms), Deg(-0.5));
+
+        let angle = Deg(-122.4194);
+        assert_relative_eq!(Deg::from(DmsRepr::from(angle)), angle, epsilon=1e-9);
+
+        assert_tokens(&DmsRepr {degrees: 10, minutes: 20, seconds: 30.5, negative: true}, &[
+            Token::Struct {name: "DmsRepr", len: 4},
+            Token::Str("degrees"), Token::I32(10),
+            Token::Str("minutes"), Token::U32(20),
+            Token::Str("seconds"), Token::F64(30.5),
+            Token::Str("negative"), Token::Bool(true),
+            Token::StructEnd,
+        ]);
+    }
+
+    #[test]
+    fn test_consts() {
+        use super::consts::*;
+
+        assert_relative_eq!(Rad::from(DEG_90), RAD_FRAC_PI_2);
+        assert_relative_eq!(Rad::from(DEG_45), RAD_FRAC_PI_4);
+        assert_relative_eq!(Rad::from(DEG_60), RAD_FRAC_PI_3);
+        assert_relative_eq!(Rad::from(DEG_30), RAD_FRAC_PI_6);
+        assert_relative_eq!(Deg::from(RAD_PI), DEG_180);
+        assert_relative_eq!(Deg::from(TURNS_QUARTER), DEG_90);
+        assert_relative_eq!(Deg::from(TURNS_HALF), DEG_180);
+        assert_relative_eq!(Turns::from(DEG_270), Turns(0.75));
+        assert_eq!(TURNS_FULL, Turns::full_turn());
+    }
+
+    #[test]
+    fn test_is_cardinal() {
+        for &angle in &[0.0, 90.0, 180.0, 270.0, 360.0, -90.0] {
+            assert!(Deg(angle).is_cardinal(1e-9));
+            assert!(!Deg(angle).is_intercardinal(1e-9));
+        }
+        for &angle in &[45.0, 135.0, 225.0, 315.0, -45.0] {
+            assert!(Deg(angle).is_intercardinal(1e-9));
+            assert!(!Deg(angle).is_cardinal(1e-9));
+        }
+        assert!(Deg(90.001).is_cardinal(1e-2));
+        assert!(Deg(359.999).is_cardinal(1e-2));
+        assert!(!Deg(90.1).is_cardinal(1e-2));
+        assert!(Deg(44.995).is_intercardinal(1e-2));
+        assert!(!Deg(46.0).is_intercardinal(1e-2));
+        assert!(Rad::pi_over_2().is_cardinal(1e-12));
+    }
+
+    #[test]
+    fn test_wrapping_scale() {
+        assert_relative_eq!(Deg(100.0).wrapping_scale(37.0), Deg(100.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg(90.0).wrapping_scale(5.0), Deg(90.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg(100.0).wrapping_scale(-1.0), Deg(260.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg(45.0).wrapping_scale(-3.0), Deg(225.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg(730.0).wrapping_scale(2.0), Deg(20.0), epsilon = 1e-9);
+        assert_relative_eq!(Turns(0.25).wrapping_scale(0.5), Turns(0.125));
+        assert_relative_eq!(Deg(370.0).wrapping_scale(0.5), Deg(185.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg(-10.0).wrapping_scale(0.5), Deg(355.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg(-100.0).wrapping_scale(1.5), Deg(210.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg(540.0).wrapping_scale(-0.25), Deg(225.0), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_normalized_hash_set() {
+        use std::collections::HashSet;
+
+        let mut set = HashSet::new();
+        set.insert(Normalized::new(Deg(10.0)));
+        set.insert(Normalized::new(Deg(370.0)));
+        set.insert(Normalized::new(Deg(-350.0)));
+        set.insert(Normalized::new(Deg(730.0)));
+        assert_eq!(set.len(), 1);
+        assert!(set.contains(&Normalized::new(Deg(10.0))));
+        assert!(!set.contains(&Normalized::new(Deg(11.0))));
+        assert_eq!(Normalized::new(Deg(370.0)).into_inner(), Deg(10.0));
+    }
+
+    #[test]
+    fn test_normalized_non_finite() {
+        use std::collections::HashSet;
+
+        let nan = Normalized::new(Deg(f64::NAN));
+        assert_eq!(nan, Normalized::new(Deg(f64::NAN)));
+        assert_eq!(nan, Normalized::new(Deg(f64::INFINITY)));
+        assert!(nan != Normalized::new(Deg(0.0)));
+
+        let mut set = HashSet::new();
+        set.insert(nan);
+        set.insert(Normalized::new(Deg(f64::NEG_INFINITY)));
+        set.insert(Normalized::new(Deg(0.0)));
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(&Normalized::new(Deg(f64::NAN))));
+    }
+
+    #[test]
+    fn test_from_point() {
+        assert_relative_eq!(Deg::from_point((1.0, 0.0)), Deg(0.0));
+        assert_relative_eq!(Deg::from_point((0.0, 1.0)), Deg(90.0));
+        assert_relative_eq!(Deg::from_point((-1.0, 0.0)), Deg(180.0));
+        assert_relative_eq!(Deg::from_point((0.0, -1.0)), Deg(270.0));
+        assert_relative_eq!(Deg::from_point((0.0, 0.0)), Deg(0.0));
+        assert_relative_eq!(Turns::from_point((2.0, 2.0)), Turns(0.125));
+    }
+
+    #[test]
+    fn test_sample_keyframes() {
+        let keys = [(0.0, Deg(350.0)), (1.0, Deg(10.0)), (3.0, Deg(90.0))];
+        assert_eq!(sample_keyframes(&keys, -1.0), Some(Deg(350.0)));
+        assert_relative_eq!(sample_keyframes(&keys, 0.5).unwrap().normalize(), Deg(0.0),
+                            epsilon = 1e-9);
+        assert_relative_eq!(sample_keyframes(&keys, 1.0).unwrap(), Deg(10.0));
+        assert_relative_eq!(sample_keyframes(&keys, 2.0).unwrap(), Deg(50.0));
+        assert_eq!(sample_keyframes(&keys, 5.0), Some(Deg(90.0)));
+        assert_eq!(sample_keyframes::<Deg<f64>>(&[], 1.0), None);
+        assert_eq!(sample_keyframes(&keys, f64::NAN), None);
+    }
+
+    #[test]
+    fn test_relative_to() {
+        assert_relative_eq!(Deg(100.0).relative_to(Deg(90.0)), Deg(10.0));
+        assert_relative_eq!(Deg(100.0).relative_to(Deg(110.0)), Deg(-10.0));
+        assert_relative_eq!(Deg(5.0).relative_to(Deg(355.0)), Deg(10.0));
+        assert_relative_eq!(Deg(355.0).relative_to(Deg(5.0)), Deg(-10.0));
+        assert_relative_eq!(Deg(90.0).relative_to(Rad(consts::PI)), Deg(-90.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_absolute_from() {
+        assert_relative_eq!(Deg(10.0).absolute_from(Deg(90.0)), Deg(100.0));
+        assert_relative_eq!(Deg(-10.0).absolute_from(Deg(5.0)), Deg(355.0));
+
+        for &(heading, base) in &[(100.0, 90.0), (5.0, 355.0), (-30.0, 720.0), (400.0, 200.0)] {
+            let heading = Deg(heading);
+            let base = Deg(base);
+            assert_relative_eq!(heading.relative_to(base).absolute_from(base), heading.normalize(),
+                                epsilon = 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_angle_range_display() {
+        let range = AngleRange::new(Deg(10.0), Deg(80.0));
+        assert_relative_eq!(range.arc_length(), Deg(70.0));
+        assert_eq!(format!("{}", range), "10°..80° [70° arc]");
+        assert_eq!(format!("{:.2}", range), "10.00°..80.00° [70.00° arc]");
+
+        let wrapping = AngleRange::new(Deg(350.0), Deg(20.0));
+        assert_relative_eq!(wrapping.arc_length(), Deg(30.0));
+        assert_eq!(format!("{}", wrapping), "350°..20° [30° arc]");
+        assert_eq!(format!("{:.1}", AngleRange::new(Gon(390.0), Gon(10.0))),
+                   "390.0gon..10.0gon [20.0gon arc]");
+    }
+
+    #[test]
+    fn test_sinc() {
+        assert_eq!(Rad(0.0).sinc(), 1.0);
+        assert_eq!(Deg(0.0).sinc(), 1.0);
+        assert_relative_eq!(Rad(consts::PI).sinc(), 0.0, epsilon = 1e-15);
+        assert_relative_eq!(Deg(180.0).sinc(), 0.0, epsilon = 1e-15);
+        assert_relative_eq!(Rad(consts::PI / 2.0).sinc(), 2.0 / consts::PI);
+    }
+
+    #[test]
+    fn test_limit_step() {
+        assert_relative_eq!(Deg(10.0).limit_step(Deg(50.0), Deg(15.0)), Deg(25.0));
+        assert_relative_eq!(Deg(10.0).limit_step(Deg(50.0), Deg(90.0)), Deg(50.0));
+        assert_relative_eq!(Deg(50.0).limit_step(Deg(10.0), Deg(15.0)), Deg(35.0));
+        assert_relative_eq!(Deg(350.0).limit_step(Deg(20.0), Deg(15.0)), Deg(365.0));
+        assert_relative_eq!(Deg(10.0).limit_step(Deg(340.0), Deg(15.0)), Deg(-5.0));
+
+        let mut limited = Deg(0.0);
+        for _ in 0..3 {
+            limited = limited.limit_step(Deg(90.0), Deg(20.0));
+        }
+        assert_relative_eq!(limited, Deg(60.0));
+        assert_relative_eq!(limited.limit_step(Deg(90.0), Deg(360.0)), Deg(90.0));
+    }
+
+    #[test]
+    fn test_mil() {
+        assert_relative_eq!(Deg(0.0).to_mil(), 0.0);
+        assert_relative_eq!(Deg(90.0).to_mil(), 1600.0);
+        assert_relative_eq!(Deg(180.0).to_mil(), 3200.0);
+        assert_relative_eq!(Deg(270.0).to_mil(), 4800.0);
+        assert_relative_eq!(Rad(consts::PI).to_mil(), 3200.0);
+        assert_relative_eq!(Deg::from_mil(1600.0), Deg(90.0));
+        assert_relative_eq!(Gon::from_mil(Gon(123.4).to_mil()), Gon(123.4), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_is_near_zero() {
+        assert!(Deg(0.0).is_near_zero(0.0));
+        assert!(Deg(0.0001).is_near_zero(1e-3));
+        assert!(Deg(359.9999).is_near_zero(1e-3));
+        assert!(Deg(-0.0001).is_near_zero(1e-3));
+        assert!(Deg(720.0005).is_near_zero(1e-3));
+        assert!(!Deg(0.01).is_near_zero(1e-3));
+        assert!(!Deg(180.0).is_near_zero(1e-3));
+    }
+
+    #[test]
+    fn test_running_blend() {
+        let noisy = [Deg(350.0), Deg(10.0), Deg(345.0), Deg(15.0), Deg(355.0), Deg(5.0)];
+        let smoothed = running_blend(&noisy, 0.25);
+        assert_eq!(smoothed.len(), noisy.len());
+        assert_eq!(smoothed[0], Deg(350.0));
+        assert_relative_eq!(smoothed[1], Deg(355.0), epsilon = 1e-9);
+
+        let total_variation = |seq: &[Deg<f64>]| -> f64 {
+            seq.windows(2).map(|pair| pair[1].delta(&pair[0]).0.abs()).sum()
+        };
+        assert!(total_variation(&smoothed) < total_variation(&noisy) / 2.0);
+        for angle in &smoothed {
+            assert!(angle.0 >= 0.0 && angle.0 < 360.0);
+        }
+        assert!(running_blend::<Deg<f64>>(&[], 0.5).is_empty());
+    }
+
+    #[test]
+    fn test_try_into_angle_lossy() {
+        let rad: Rad<f32> = Deg(90.0f64).try_into_angle_lossy(1e-4).unwrap();
+        assert_relative_eq!(rad, Rad(consts::PI as f32 / 2.0));
+        let rad: Rad<f64> = Deg(45.0f64).try_into_angle_lossy(1e-12).unwrap();
+        assert_relative_eq!(rad, Rad(consts::PI / 4.0));
+
+        let lossy: Result<Deg<f32>, _> = Deg(123.456789012345f64).try_into_angle_lossy(1e-12);
+        assert_eq!(lossy, Err(AngleError::PrecisionLoss));
+        let lossy: Result<Rad<f32>, _> = Deg(1e300f64).try_into_angle_lossy(1.0);
+        assert_eq!(lossy, Err(AngleError::PrecisionLoss));
+    }
+
+    #[test]
+    fn test_inverse_interpolate() {
+        assert_relative_eq!(Deg::inverse_interpolate(&Deg(45.0), Deg(0.0), Deg(90.0)), 0.5);
+        assert_relative_eq!(Deg::inverse_interpolate(&Deg(0.0), Deg(0.0), Deg(90.0)), 0.0);
+        assert_relative_eq!(Deg::inverse_interpolate(&Deg(90.0), Deg(0.0), Deg(90.0)), 1.0);
+        assert_relative_eq!(Deg::inverse_interpolate(&Deg(0.0), Deg(350.0), Deg(30.0)), 0.25);
+        assert_relative_eq!(Deg::inverse_interpolate(&Deg(20.0), Deg(30.0), Deg(350.0)), 0.25);
+        assert_relative_eq!(Deg::inverse_interpolate(&Deg(120.0), Deg(0.0), Deg(90.0)), 4.0 / 3.0);
+        assert_relative_eq!(Deg::inverse_interpolate(&Deg(90.0), Deg(0.0), Deg(180.0)), 0.5);
+        assert_relative_eq!(Deg::inverse_interpolate(&Deg(45.0), Deg(10.0), Deg(10.0)), 0.0);
+
+        let (start, end) = (Deg(300.0), Deg(60.0));
+        for &pos in &[0.0, 0.2, 0.5, 0.9, 1.0] {
+            let value = start.interpolate(&end, pos);
+            assert_relative_eq!(Deg::inverse_interpolate(&value, start, end), pos, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_checked_div() {
+        assert_eq!(Deg(90.0).checked_div(2.0), Some(Deg(45.0)));
+        assert_eq!(Deg(90.0).checked_div(-3.0), Some(Deg(-30.0)));
+        assert_eq!(Deg(90.0).checked_div(0.0), None);
+        assert_eq!(Deg(0.0).checked_div(0.0), None);
+        assert_eq!(Deg(90.0).checked_div(f64::NAN), None);
+        assert_eq!(Deg(f64::INFINITY).checked_div(2.0), None);
+    }
+
+    #[test]
+    fn test_turn_deltas() {
+        let path = [Deg(10.0), Deg(80.0), Deg(350.0), Deg(20.0), Deg(200.0)];
+        let deltas = turn_deltas(&path);
+        assert_eq!(deltas.len(), 4);
+        assert_relative_eq!(deltas[0], Deg(70.0));
+        assert_relative_eq!(deltas[1], Deg(-90.0));
+        assert_relative_eq!(deltas[2], Deg(30.0));
+        assert_relative_eq!(deltas[3], Deg(-180.0));
+        assert!(turn_deltas(&[Deg(10.0)]).is_empty());
+        assert!(turn_deltas::<Deg<f64>>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_to_same_unit() {
+        let (left, right) = Deg(90.0).to_same_unit(Rad(consts::PI));
+        assert_eq!(left, Deg(90.0));
+        assert_relative_eq!(right, Deg(180.0));
+
+        let (left, right) = Turns(0.5).to_same_unit(Gon(100.0));
+        assert_eq!(left, Turns(0.5));
+        assert_relative_eq!(right, Turns(0.25));
+    }
+
+    #[test]
+    fn test_smoothstep() {
+        let (start, end) = (Deg(30.0), Deg(90.0));
+        assert_eq!(start.smoothstep(&end, 0.0), start);
+        assert_eq!(start.smoothstep(&end, 1.0), end);
+        assert_relative_eq!(start.smoothstep(&end, 0.5), Deg(60.0));
+        assert_relative_eq!(start.smoothstep(&end, 0.25), Deg(30.0 + 60.0 * 0.15625));
+
+        assert_eq!(start.smootherstep(&end, 0.0), start);
+        assert_eq!(start.smootherstep(&end, 1.0), end);
+        assert_relative_eq!(start.smootherstep(&end, 0.5), Deg(60.0));
+        assert_relative_eq!(start.smootherstep(&end, 0.25), Deg(30.0 + 60.0 * 0.103515625));
+
+        assert_relative_eq!(Deg(350.0).smoothstep(&Deg(10.0), 0.5).normalize(), Deg(0.0),
+                            epsilon = 1e-9);
+        assert_eq!(start.smoothstep(&end, 2.0), end);
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        assert_relative_eq!(Deg(350.0).weighted_mean(&Deg(10.0), 0.5), Deg(0.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg(350.0).weighted_mean(&Deg(10.0), 0.0), Deg(350.0));
+        assert_relative_eq!(Deg(350.0).weighted_mean(&Deg(10.0), 0.25), Deg(355.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg(350.0).weighted_mean(&Deg(10.0), 1.0), Deg(10.0), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_direction_to() {
+        let origin = (1.0, 1.0);
+        assert_relative_eq!(direction_to::<Deg<f64>>(origin, (2.0, 1.0)), Deg(0.0));
+        assert_relative_eq!(direction_to::<Deg<f64>>(origin, (1.0, 2.0)), Deg(90.0));
+        assert_relative_eq!(direction_to::<Deg<f64>>(origin, (0.0, 1.0)), Deg(180.0));
+        assert_relative_eq!(direction_to::<Deg<f64>>(origin, (1.0, 0.0)), Deg(270.0));
+        assert_relative_eq!(direction_to::<Deg<f64>>(origin, (0.0, 0.0)), Deg(225.0));
+        assert_relative_eq!(direction_to::<Deg<f64>>(origin, origin), Deg(0.0));
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_relative_eq!(Deg(90.0).to_percent(), 25.0);
+        assert_relative_eq!(Deg(0.0).to_percent(), 0.0);
+        assert_relative_eq!(Deg(450.0).to_percent(), 25.0);
+        assert_relative_eq!(Deg(-90.0).to_percent(), 75.0);
+        assert_relative_eq!(Deg::from_percent(50.0), Deg(180.0));
+        assert_relative_eq!(Deg::from_percent(150.0), Deg(540.0));
+        assert_relative_eq!(Rad::from_percent(Rad(1.0).to_percent()), Rad(1.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_back_bearing() {
+        assert_relative_eq!(Deg(30.0).back_bearing(), Deg(210.0));
+        assert_relative_eq!(Deg(200.0).back_bearing(), Deg(20.0));
+        assert_relative_eq!(Deg(180.0).back_bearing(), Deg(0.0));
+        assert_relative_eq!(Deg(-90.0).back_bearing(), Deg(90.0));
+        assert_relative_eq!(Deg(725.0).back_bearing(), Deg(185.0), epsilon = 1e-9);
+        assert_relative_eq!(Turns(0.75).back_bearing(), Turns(0.25));
+    }
+
+    #[test]
+    fn test_snap_to() {
+        assert_relative_eq!(Deg(47.0).snap_to(Deg(15.0)).unwrap(), Deg(45.0));
+        assert_relative_eq!(Deg(53.0).snap_to(Deg(15.0)).unwrap(), Deg(60.0));
+        assert_relative_eq!(Deg(52.5).snap_to(Deg(15.0)).unwrap(), Deg(60.0));
+        assert_relative_eq!(Deg(359.0).snap_to(Deg(15.0)).unwrap(), Deg(0.0));
+        assert_relative_eq!(Deg(-20.0).snap_to(Deg(90.0)).unwrap(), Deg(0.0));
+        assert_relative_eq!(Deg(400.0).snap_to(Deg(90.0)).unwrap(), Deg(0.0));
+        assert_relative_eq!(Deg(130.0).snap_to(Deg(90.0)).unwrap(), Deg(90.0));
+
+        assert_relative_eq!(Deg(352.0).snap_to(Deg(70.0)).unwrap(), Deg(350.0));
+        assert_relative_eq!(Deg(356.0).snap_to(Deg(70.0)).unwrap(), Deg(0.0));
+
+        assert_eq!(Deg(47.0).snap_to(Deg(0.0)), None);
+        assert_eq!(Deg(47.0).snap_to(Deg(f64::NAN)), None);
+        assert_eq!(Deg(47.0).snap_to(Deg(f64::INFINITY)), None);
+        assert_eq!(Deg(f64::NAN).snap_to(Deg(15.0)), None);
+    }
+
+    #[test]
+    fn test_checked_from_components() {
+        assert_relative_eq!(
+            Deg::checked_from_components(Deg(50.0), ArcMinutes(30.0), ArcSeconds(36.0)).unwrap(),
+            Deg(50.51));
+        assert!(Deg::checked_from_components(Deg(-5.0), ArcMinutes(0.0), ArcSeconds(59.9)).is_ok());
+        assert_eq!(Deg::checked_from_components(Deg(50.0), ArcMinutes(90.0), ArcSeconds(0.0)),
+                   Err(AngleError::OutOfRange));
+        assert_eq!(Deg::checked_from_components(Deg(50.0), ArcMinutes(60.0), ArcSeconds(0.0)),
+                   Err(AngleError::OutOfRange));
+        assert_eq!(Deg::checked_from_components(Deg(50.0), ArcMinutes(10.0), ArcSeconds(-1.0)),
+                   Err(AngleError::OutOfRange));
+        assert_eq!(Deg::checked_from_components(Deg(50.0), ArcMinutes(f64::NAN), ArcSeconds(0.0)),
+                   Err(AngleError::OutOfRange));
+    }
+
+    #[test]
+    fn test_to_compass32_name() {
+        assert_eq!(Deg(0.0).to_compass32_name(), Some("N"));
+        assert_eq!(Deg(11.25).to_compass32_name(), Some("N by E"));
+        assert_eq!(Deg(33.75).to_compass32_name(), Some("NE by N"));
+        assert_eq!(Deg(45.0).to_compass32_name(), Some("NE"));
+        assert_eq!(Deg(185.0).to_compass32_name(), Some("S"));
+        assert_eq!(Deg(348.75).to_compass32_name(), Some("N by W"));
+        assert_eq!(Deg(359.0).to_compass32_name(), Some("N"));
+        assert_eq!(Turns(0.5).to_compass32_name(), Some("S"));
+        assert_eq!(Deg(f64::NAN).to_compass32_name(), None);
+        assert_eq!(Deg(f64::NEG_INFINITY).to_compass32_name(), None);
+    }
+
+    #[test]
+    fn test_scalar_mul() {
+        assert_eq!(2.0 * Deg(30.0), Deg(60.0));
+        assert_eq!(2.0 * Deg(30.0), Deg(30.0) * 2.0);
+        assert_eq!(0.5f32 * Rad(2.0f32), Rad(1.0f32));
+        assert_eq!(3.0 * Revolutions(0.5), Revolutions(1.5));
+    }
+
+    #[test]
+    fn test_interpolate_with_arc() {
+        let (angle, arc) = Deg(350.0).interpolate_with_arc(&Deg(30.0), 0.25);
+        assert_relative_eq!(angle.normalize(), Deg(0.0), epsilon = 1e-9);
+        assert_relative_eq!(arc, Deg(40.0));
+
+        for &(start, end) in &[(0.0, 90.0), (10.0, 300.0), (300.0, 10.0), (0.0, 180.0), (45.0, 45.0)] {
+            let (start, end) = (Deg(start), Deg(end));
+            let (_, arc) = start.interpolate_with_arc(&end, 0.5);
+            assert_relative_eq!(arc, start.abs_diff(&end));
+            assert!(arc <= Deg::half_turn());
+        }
+    }
+
+    #[test]
+    fn test_as_unit() {
+        let reading = Turns(0.25);
+        assert_relative_eq!(reading.as_deg(), Deg(90.0));
+        assert_relative_eq!(reading.as_rad(), Rad(consts::PI / 2.0));
+        assert_relative_eq!(reading.as_turns(), Turns(0.25));
+        assert_relative_eq!(reading.as_rad().as_deg().as_turns(), reading);
+    }
+
+    #[test]
+    fn test_sort_by_bearing() {
+        let mut points = [(0.0, -1.0), (-1.0, 0.0), (1.0, 0.0), (0.0, 1.0)];
+        sort_by_bearing::<Deg<f64>>((0.0, 0.0), &mut points);
+        assert_eq!(points, [(1.0, 0.0), (0.0, 1.0), (-1.0, 0.0), (0.0, -1.0)]);
+
+        let mut points = [(3.0, 1.0), (1.0, 3.0), (2.0, 2.5)];
+        sort_by_bearing::<Rad<f64>>((2.0, 2.0), &mut points);
+        assert_eq!(points, [(2.0, 2.5), (1.0, 3.0), (3.0, 1.0)]);
+    }
+
+    #[test]
+    fn test_normalized_ref() {
+        let angle = Deg(45.0);
+        match angle.normalized_ref() {
+            Cow::Borrowed(borrowed) => assert!(std::ptr::eq(borrowed, &angle)),
+            Cow::Owned(_) => panic!("expected a borrowed angle"),
+        }
+        match Deg(405.0).normalized_ref() {
+            Cow::Owned(owned) => assert_relative_eq!(owned, Deg(45.0)),
+            Cow::Borrowed(_) => panic!("expected an owned angle"),
+        }
+        assert!(matches!(Deg(-45.0).normalized_ref(), Cow::Owned(Deg(_))));
+    }
+
+    #[test]
+    fn test_atan2_normalized() {
+        assert_relative_eq!(Deg::atan2_normalized(1.0, 1.0), Deg(45.0));
+        assert_relative_eq!(Deg::atan2_normalized(1.0, -1.0), Deg(135.0));
+        assert_relative_eq!(Deg::atan2_normalized(-1.0, -1.0), Deg(225.0));
+        assert_relative_eq!(Deg::atan2_normalized(-1.0, 1.0), Deg(315.0));
+        assert_relative_eq!(Deg::atan2_normalized(-1.0, 0.0), Deg(270.0));
+        assert!(Rad::atan2_normalized(-1e-300, 1.0).is_normalized());
+    }
+
+    #[test]
+    fn test_copysign() {
+        assert_eq!(Deg(30.0).copysign(Deg(-5.0)), Deg(-30.0));
+        assert_eq!(Deg(-30.0).copysign(Deg(5.0)), Deg(30.0));
+        assert_eq!(Deg(30.0).copysign(Deg(0.0)), Deg(30.0));
+        assert_eq!(Deg(30.0).copysign(Deg(-0.0)), Deg(-30.0));
+        assert_eq!(Deg(30.0).copysign(Rad(-1.0)), Deg(-30.0));
+    }
+
+    #[test]
+    fn test_angle_stats() {
+        let stream = [Deg(350.0), Deg(5.0), Deg(20.0), Deg(340.0), Deg(15.0)];
+        let mut stats = AngleStats::new();
+        assert_eq!(stats.count(), 0);
+        assert_eq!(stats.resultant_length(), 0.0);
+
+        for (i, angle) in stream.iter().enumerate() {
+            stats.push(*angle);
+            assert_eq!(stats.count(), i + 1);
+            let batch: Deg<f64> = mean(stream[..=i].iter().cloned());
+            assert_relative_eq!(stats.circular_mean(), batch, epsilon = 1e-9);
+        }
+        assert!(stats.resultant_length() > 0.9 && stats.resultant_length() <= 1.0);
+
+        let mut opposed = AngleStats::new();
+        opposed.push(Deg(0.0));
+        opposed.push(Deg(180.0));
+        assert_relative_eq!(opposed.resultant_length(), 0.0, epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_from_scalar() {
+        let deg: Deg<f64> = 90.0.into();
+        assert_eq!(deg, Deg(90.0));
+        assert_eq!(Deg::from(90.0), Deg(90.0));
+        let gon: Gon<f64> = 100.0.into();
+        assert_eq!(gon, Gon(100.0));
+        let rad: Rad<f32> = 1.5f32.into();
+        assert_eq!(rad, Rad(1.5f32));
+        let turns: Turns<f64> = 0.25.into();
+        assert_eq!(turns, Turns(0.25));
+        let mins: ArcMinutes<f64> = 30.0.into();
+        assert_eq!(mins, ArcMinutes(30.0));
+        let secs: ArcSeconds<f64> = 15.0.into();
+        assert_eq!(secs, ArcSeconds(15.0));
+        let revs: Revolutions<f64> = 2.0.into();
+        assert_eq!(revs, Revolutions(2.0));
+    }
+
+    #[test]
+    fn test_clamp_to_domain() {
+        assert_eq!(Deg(-10.0).clamp_to_domain(), Deg(0.0));
+        assert_eq!(Deg(123.0).clamp_to_domain(), Deg(123.0));
+        assert_eq!(Deg(0.0).clamp_to_domain(), Deg(0.0));
+
+        let clamped = Deg(400.0).clamp_to_domain();
+        assert!(clamped.is_normalized());
+        assert_relative_eq!(clamped, Deg(360.0), epsilon = 1e-9);
+        assert!(Deg(360.0).clamp_to_domain().is_normalized());
+        assert!(Rad(10.0f32).clamp_to_domain().is_normalized());
+        assert!(Deg(f64::NAN).clamp_to_domain().0.is_nan());
+    }
+
+    #[test]
+    fn test_sin_cos_tan() {
+        for &angle in &[0.0, 30.0, 45.0, 135.0, 200.0, 89.999, 90.001, -60.0] {
+            let angle = Deg(angle);
+            let (sin, cos, tan) = angle.sin_cos_tan();
+            assert_relative_eq!(sin, angle.sin(), epsilon = 1e-12);
+            assert_relative_eq!(cos, angle.cos(), epsilon = 1e-12);
+            assert_relative_eq!(tan, angle.tan(), epsilon = 1e-9, max_relative = 1e-9);
+        }
+        let (_, _, tan) = Deg(90.0).sin_cos_tan();
+        assert!(tan.abs() > 1e15);
+    }
+
+    #[test]
+    fn test_grade_percent() {
+        assert_relative_eq!(Deg(0.0).to_grade_percent(), 0.0);
+        assert_relative_eq!(Deg(45.0).to_grade_percent(), 100.0, epsilon = 1e-12);
+        assert_relative_eq!(Deg(80.0).to_grade_percent(), 567.128181961771, epsilon = 1e-9);
+        assert_relative_eq!(Deg(-45.0).to_grade_percent(), -100.0, epsilon = 1e-12);
+        assert_eq!(Deg(90.0).to_grade_percent(), f64::INFINITY);
+        assert_eq!(Deg(-90.0).to_grade_percent(), f64::NEG_INFINITY);
+
+        assert_relative_eq!(Deg::from_grade_percent(0.0), Deg(0.0));
+        assert_relative_eq!(Deg::from_grade_percent(100.0), Deg(45.0), epsilon = 1e-12);
+        assert_relative_eq!(Deg::from_grade_percent(567.128181961771), Deg(80.0), epsilon = 1e-9);
+        assert_relative_eq!(Deg::from_grade_percent(f64::INFINITY), Deg(90.0));
+    }
+
+    #[test]
+    fn test_normalize_iter() {
+        let readings = vec![Deg(-10.0), Deg(45.0), Deg(725.0), Deg(360.0)];
+        let normalized: Vec<_> = readings.iter().cloned().normalized().collect();
+        assert_eq!(normalized.len(), readings.len());
+        for angle in &normalized {
+            assert!(angle.is_normalized());
+        }
+        assert_eq!(normalized, wrap_all(&readings));
+        assert_eq!(readings.into_iter().normalized().size_hint(), (4, Some(4)));
+    }
+
+    #[test]
+    fn test_to_time_of_day() {
+        assert_eq!(Turns(0.0).to_time_of_day(), (0, 0, 0.0));
+        assert_eq!(Turns(0.25).to_time_of_day(), (6, 0, 0.0));
+        assert_eq!(Turns(0.5).to_time_of_day(), (12, 0, 0.0));
+        assert_eq!(Turns(1.5).to_time_of_day(), (12, 0, 0.0));
+
+        let (hours, minutes, seconds) = Turns(0.6).to_time_of_day();
+        assert_eq!((hours, minutes), (14, 24));
+        assert_relative_eq!(seconds, 0.0, epsilon = 1e-6);
+
+        let (hours, minutes, seconds) = Turns((13.0 * 3600.0 + 59.0 * 60.0 + 59.5) / 86400.0)
+            .to_time_of_day();
+        assert_eq!((hours, minutes), (13, 59));
+        assert_relative_eq!(seconds, 59.5, epsilon = 1e-6);
+        assert_eq!(Turns(0.75f32).to_time_of_day(), (18, 0, 0.0));
+    }
+
+    #[test]
+    fn test_normalize_checked_magnitude() {
+        assert_eq!(Deg(370.0).normalize_checked_magnitude(1e6), Ok(Deg(10.0)));
+        assert_eq!(Deg(-3600.0).normalize_checked_magnitude(10.0), Ok(Deg(0.0)));
+        assert_eq!(Deg(3960.0).normalize_checked_magnitude(10.0), Err(AngleError::TooLarge));
+        assert_eq!(Deg(1e20).normalize_checked_magnitude(1e6), Err(AngleError::TooLarge));
+        assert_eq!(Deg(-1e20).normalize_checked_magnitude(1e6), Err(AngleError::TooLarge));
+        assert_eq!(Deg(f64::NAN).normalize_checked_magnitude(1e6), Err(AngleError::NonFinite));
+        assert_eq!(Deg(f64::INFINITY).normalize_checked_magnitude(1e6), Err(AngleError::NonFinite));
+    }
+
+    #[test]
+    fn test_pretty() {
+        assert_eq!(Deg(45.5).pretty(), "45.5°");
+        assert_eq!(Deg(0.0).pretty(), "0°");
+        assert_eq!(Deg(405.0).pretty(), "45°");
+        assert_eq!(Deg(0.5).pretty(), "30'");
+        assert_eq!(Deg(0.0003).pretty(), "1.08\"");
+        assert_eq!(Rad(1e-6).pretty(), "0.21\"");
+        assert_eq!(Deg(12.3456).pretty(), "12.35°");
+        assert_eq!(Deg(0.1).pretty(), "6'");
+        assert_eq!(Turns(0.1f32).pretty(), "36°");
+    }
+
+    #[test]
+    fn test_from_dms() {
+        let deg: Deg<f64> = from_dms(Deg(50.0), ArcMinutes(30.0), ArcSeconds(36.0));
+        let rad: Rad<f64> = from_dms(Deg(50.0), ArcMinutes(30.0), ArcSeconds(36.0));
+        assert_relative_eq!(deg, Deg(50.51));
+        assert_relative_eq!(rad, Rad::from_angle(Deg(50.51)));
+        assert_relative_eq!(from_dms::<_, Rad<f64>>(Deg(180.0), ArcMinutes(0.0), ArcSeconds(0.0)),
+                            Rad(consts::PI));
+    }
+
+    #[test]
+    fn test_wrapping() {
+        assert_eq!(Wrapping::new(Deg(350.0)) + Wrapping::new(Deg(20.0)), Wrapping::new(Deg(10.0)));
+        assert_eq!(Wrapping::new(Deg(10.0)) - Wrapping::new(Deg(20.0)), Wrapping::new(Deg(350.0)));
+        assert_eq!(Wrapping::new(Deg(720.0)).into_inner(), Deg(0.0));
+        assert_eq!(Wrapping::from(Deg(-90.0)).into_inner(), Deg(270.0));
+
+        let mut heading = Wrapping::new(Deg(-720.0));
+        for _ in 0..100 {
+            heading += Wrapping::new(Deg(37.0));
+            assert!(heading.into_inner().is_normalized());
+        }
+        assert_relative_eq!(heading.into_inner(), Deg(100.0), epsilon = 1e-9);
+        for _ in 0..10 {
+            heading -= Wrapping::new(Deg(410.0));
+            assert!(heading.into_inner().is_normalized());
+        }
+        assert_relative_eq!(heading.into_inner(), Deg(320.0), epsilon = 1e-9);
+    }
+
+    #[test]
+    fn test_interpolate_half_turn() {
+        assert_relative_eq!(Deg(0.0).interpolate(&Deg(180.0), 0.5), Deg(90.0));
+        assert_relative_eq!(Deg(180.0).interpolate(&Deg(0.0), 0.5).normalize(), Deg(270.0));
+        assert_relative_eq!(Deg(270.0).interpolate(&Deg(90.0), 0.5).normalize(), Deg(0.0));
+        assert_relative_eq!(Deg(90.0).interpolate(&Deg(270.0), 0.5), Deg(180.0));
+
+        assert_relative_eq!(Deg(180.0).interpolate_with_direction(&Deg(0.0), 0.5, true), Deg(270.0));
+        assert_relative_eq!(Deg(180.0).interpolate_with_direction(&Deg(0.0), 0.5, false), Deg(90.0));
+        assert_relative_eq!(Deg(350.0).interpolate_with_direction(&Deg(10.0), 0.5, true), Deg(360.0));
+        assert_relative_eq!(Deg(350.0).interpolate_with_direction(&Deg(10.0), 0.5, false), Deg(180.0));
+        assert_relative_eq!(Deg(10.0).interpolate_with_direction(&Deg(10.0), 0.5, false), Deg(10.0));
+    }
+
+    #[test]
+    fn test_scalar_in() {
+        assert_relative_eq!(Rad(consts::PI).scalar_in::<Deg<f64>>(), 180.0);
+        assert_relative_eq!(Rad(consts::PI).scalar_in::<Gon<f64>>(), 200.0);
+        assert_relative_eq!(Deg(90.0).scalar_in::<Turns<f64>>(), 0.25);
+        assert_eq!(Deg(90.0).scalar_in::<Deg<f64>>(), 90.0);
+    }
+
+    #[test]
+    fn test_reduce_to_first_quadrant() {
+        for &angle in &[30.0, 120.0, 225.0, 300.0, 0.0, 90.0, -45.0] {
+            let angle = Deg(angle);
+            let (reference, negate_sin, negate_cos, swap) = angle.reduce_to_first_quadrant();
+            assert!(reference >= Deg(0.0) && reference < Deg(90.0));
+
+            let (mut sin, mut cos) = reference.sin_cos();
+            if swap {
+                std::mem::swap(&mut sin, &mut cos);
+            }
+            if negate_sin {
+                sin = -sin;
+            }
+            if negate_cos {
+                cos = -cos;
+            }
+            assert_relative_eq!(sin, angle.sin(), epsilon = 1e-12);
+            assert_relative_eq!(cos, angle.cos(), epsilon = 1e-12);
+        }
+        assert_eq!(Deg(120.0).reduce_to_first_quadrant(), (Deg(30.0), false, true, true));
+        for &angle in &[f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+            let (reference, negate_sin, negate_cos, swap) = Deg(angle).reduce_to_first_quadrant();
+            assert!(reference.scalar().is_nan());
+            assert!(!negate_sin && !negate_cos && !swap);
+        }
     }
-}
-impl<T: fmt::Display> fmt::Display for Deg<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}°", self.0)
+
+    #[test]
+    fn test_interpolate_into() {
+        let rad: Rad<f64> = Deg(30.0).interpolate_into(&Deg(90.0), 0.5);
+        assert_relative_eq!(rad, Rad::from_angle(Deg(60.0)));
+        let rad: Rad<f64> = Deg(350.0).interpolate_into(&Deg(30.0), 0.25);
+        assert_relative_eq!(rad.normalize(), Rad(0.0), epsilon = 1e-12);
     }
-}
-impl<T: fmt::Display> fmt::Display for Gon<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}gon", self.0)
+
+    #[test]
+    fn test_angle_range_normalize() {
+        let range = AngleRange::new(Deg(-30.0), Deg(40.0)).normalize();
+        assert_relative_eq!(range.start, Deg(330.0));
+        assert_relative_eq!(range.end, Deg(400.0));
+        assert_relative_eq!(range.arc_length(), Deg(70.0));
+
+        let range = AngleRange::new(Deg(730.0), Deg(750.0)).normalize();
+        assert_relative_eq!(range.start, Deg(10.0));
+        assert_relative_eq!(range.end, Deg(30.0));
+
+        let range = AngleRange::new(Deg(45.0), Deg(90.0));
+        assert_eq!(range.normalize(), range);
     }
-}
-impl<T: fmt::Display> fmt::Display for Rad<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}r", self.0)
+
+    #[test]
+    fn test_pow_magnitude() {
+        assert_relative_eq!(Deg(4.0).pow_magnitude(0.5), Deg(2.0));
+        assert_relative_eq!(Deg(-4.0).pow_magnitude(0.5), Deg(-2.0));
+        assert_relative_eq!(Deg(3.0).pow_magnitude(1.0), Deg(3.0));
+        assert_relative_eq!(Deg(-3.0).pow_magnitude(1.0), Deg(-3.0));
+        assert_relative_eq!(Deg(3.0).pow_magnitude(2.0), Deg(9.0));
+        assert_relative_eq!(Deg(357.0).pow_magnitude(2.0), Deg(-9.0), epsilon = 1e-9);
+        assert_eq!(Deg(0.0).pow_magnitude(0.5), Deg(0.0));
+        assert_eq!(Deg(0.0).pow_magnitude(0.0), Deg(0.0));
     }
-}
-impl<T: fmt::Display> fmt::Display for Turns<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.0)
+
+    #[test]
+    fn test_trig_checked() {
+        for &angle in &[0.0, 30.0, 100.0, -250.0, 7200.0] {
+            let angle = Deg(angle);
+            assert_eq!(angle.sin_checked(), angle.sin());
+            assert_eq!(angle.cos_checked(), angle.cos());
+            assert_eq!(angle.tan_checked(), angle.tan());
+            assert_eq!(angle.sin_cos_checked(), angle.sin_cos());
+        }
     }
-}
-impl<T: fmt::Display> fmt::Display for ArcMinutes<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}'", self.0)
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "is a normalize missing")]
+    fn test_trig_checked_huge_input() {
+        Deg(1e12).sin_checked();
     }
-}
-impl<T: fmt::Display> fmt::Display for ArcSeconds<T> {
-    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}\"", self.0)
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "not finite")]
+    fn test_trig_checked_non_finite_input() {
+        Rad(f64::NAN).cos_checked();
     }
-}
 
-/// Compute the mean of a collection of angles.
-///
-/// Note that because angles are circular, a standard summation and dividing by `len()`
-/// is _not_ valid, even if all angles are normalized.
-pub fn mean<T, Scalar, Out>(iter: T) -> Out
-    where T: IntoIterator,
-          T::Item: IntoAngle<Rad<Scalar>, OutputScalar=Scalar>,
-          Scalar: Float + AddAssign,
-          Out: Angle<Scalar=Scalar> + FromAngle<Rad<Scalar>>,
-{
-    let mut sum_of_sines: Scalar = cast(0.0).unwrap();
-    let mut sum_of_cosines: Scalar = cast(0.0).unwrap();
+    #[test]
+    fn test_arc_length() {
+        assert_relative_eq!(Rad(consts::PI).arc_length(2.0), 2.0 * consts::PI);
+        assert_relative_eq!(Deg(360.0).arc_length(1.0), 2.0 * consts::PI);
+        assert_relative_eq!(Turns(1.0).arc_length(3.0), 6.0 * consts::PI);
+        assert_relative_eq!(Deg(180.0).arc_length(1.0), consts::PI);
+        assert_relative_eq!(Deg(180.0).arc_length(0.5), consts::PI / 2.0);
+        assert_relative_eq!(Deg(-90.0).arc_length(2.0), -consts::PI);
+    }
 
-    for angle in iter.into_iter() {
-        let intermediate_angle: Rad<Scalar> = angle.into_angle();
-        let (sin, cos) = intermediate_angle.sin_cos();
-        sum_of_sines += sin;
-        sum_of_cosines += cos;
+    #[test]
+    fn test_from_arc_length() {
+        assert_relative_eq!(Rad::from_arc_length(consts::PI, 1.0), Rad(consts::PI));
+        assert_relative_eq!(Deg::from_arc_length(consts::PI, 2.0), Deg(90.0));
+        for &(angle, radius) in &[(30.0, 1.0), (200.0, 0.25), (-45.0, 10.0)] {
+            let angle = Deg(angle);
+            assert_relative_eq!(Deg::from_arc_length(angle.arc_length(radius), radius), angle,
+                                epsilon = 1e-12);
+        }
+        assert_eq!(Deg::from_arc_length(1.0, 0.0).0, f64::INFINITY);
+        assert!(Deg::from_arc_length(0.0, 0.0).0.is_nan());
     }
 
-    Out::atan2(sum_of_sines, sum_of_cosines).normalize()
-}
+    #[test]
+    fn test_chord_length() {
+        assert_relative_eq!(Deg(60.0).chord_length(1.0), 1.0, epsilon = 1e-12);
+        assert_relative_eq!(Deg(90.0).chord_length(1.0), 2.0f64.sqrt(), epsilon = 1e-12);
+        assert_relative_eq!(Deg(180.0).chord_length(1.0), 2.0, epsilon = 1e-12);
+        assert_relative_eq!(Rad(consts::PI).chord_length(3.0), 6.0, epsilon = 1e-12);
+        assert_relative_eq!(Deg(0.0).chord_length(5.0), 0.0);
+    }
 
-fn cast<T: NumCast, U: NumCast>(from: T) -> Option<U> {
-    U::from(from)
-}
+    #[cfg(feature = "proptest")]
+    proptest! {
+        #[test]
+        fn test_strategy_normalize(deg in strategy::any_deg(), rad in strategy::any_rad(),
+                                   turns in strategy::any_turns(),
+                                   secs in strategy::any_arc_seconds()) {
+            prop_assert!(deg.normalize().is_normalized());
+            prop_assert!(rad.normalize().is_normalized());
+            prop_assert!(turns.normalize().is_normalized());
+            prop_assert!(secs.normalize().is_normalized());
+        }
+    }
 
-#[cfg(test)]
-mod test {
-    #[cfg(feature = "serde")]
-    extern crate serde_test;
+    #[test]
+    fn test_try_from_angle() {
+        let rad = Rad::<f64>::try_from_angle(Deg(90.0));
+        assert_relative_eq!(rad.unwrap(), Rad(consts::PI / 2.0));
+        assert_eq!(Rad::<f64>::try_from_angle(Deg(f64::NAN)), Err(AngleError::NonFinite));
+        assert_eq!(Rad::<f64>::try_from_angle(Deg(f64::INFINITY)), Err(AngleError::NonFinite));
+        assert_eq!(ArcSeconds::<f64>::try_from_angle(Deg(f64::MAX)), Err(AngleError::NonFinite));
+        assert_eq!(Deg::<f64>::try_from_angle(Deg(45.0)), Ok(Deg(45.0)));
+    }
 
-    use std::f64::consts;
-    use std::f64;
-    use super::*;
+    #[test]
+    fn test_interpolate_samples() {
+        let frames = Deg(340.0).interpolate_samples(&Deg(20.0), 5);
+        let expected = [Deg(340.0), Deg(350.0), Deg(0.0), Deg(10.0), Deg(20.0)];
+        assert_eq!(frames.len(), expected.len());
+        for (frame, expected) in frames.iter().zip(expected.iter()) {
+            assert!(frame.is_normalized());
+            assert_relative_eq!(WrapApprox(*frame), WrapApprox(*expected), epsilon = 1e-9);
+        }
+        assert_eq!(Deg(370.0).interpolate_samples(&Deg(20.0), 1), vec![Deg(10.0)]);
+        assert!(Deg(10.0).interpolate_samples(&Deg(20.0), 0).is_empty());
+    }
 
     #[test]
-    fn test_construct() {
-        assert_relative_eq!(Deg(50.0), Deg::new(50.0));
-        let mut a1 = Deg(100.0);
-        let scalar = a1.scalar();
-        a1.set_scalar(scalar + 150.0);
-        assert_relative_eq!(a1, Deg(250.0));
+    fn test_direction_dot() {
+        assert_relative_eq!(Deg(30.0).direction_dot(&Deg(30.0)), 1.0);
+        assert_relative_eq!(Deg(350.0).direction_dot(&Deg(710.0)), 1.0);
+        assert_relative_eq!(Deg(30.0).direction_dot(&Deg(120.0)), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(Deg(10.0).direction_dot(&Deg(280.0)), 0.0, epsilon = 1e-12);
+        assert_relative_eq!(Deg(30.0).direction_dot(&Deg(210.0)), -1.0);
+        assert_relative_eq!(Deg(0.0).direction_dot(&Rad(consts::PI)), -1.0);
     }
 
     #[test]
-    fn test_convert() {
-        assert_relative_eq!(ArcMinutes(120.0).into_angle(), Deg(2.0), epsilon=1e-6);
-        assert_relative_eq!(ArcMinutes(120.0).into_angle(), Gon(2.222222), epsilon=1e-6);
-        assert_relative_eq!(ArcSeconds(30.0).into_angle(), ArcMinutes(0.5), epsilon=1e-6);
-        assert_relative_eq!(Deg(30.0) + ArcMinutes(30.0) + ArcSeconds(30.0), 
-            Deg(30.50833333333), epsilon=1e-6);
-        assert_relative_eq!(Rad(consts::PI).into_angle(), Deg(180.0), epsilon=1e-6);
-        assert_relative_eq!(Turns(0.25).into_angle(), Deg(90.0), epsilon=1e-6);
-        assert_relative_eq!(Turns(0.25).into_angle(), Rad(consts::PI / 2.0), epsilon=1e-6);
-        assert_relative_eq!(ArcMinutes(600.0).into_angle(), Deg(10.0), epsilon=1e-6);
-        assert_relative_eq!(ArcMinutes(5400.0).into_angle(), Rad(consts::PI / 2.0), epsilon=1e-6);
-        assert_relative_eq!(Gon(100.0).into_angle(), Deg(90.0), epsilon=1e-6);
-        assert_relative_eq!(Gon(50.0).into_angle(), Rad(consts::PI / 4.0), epsilon=1e-6);
+    fn test_circular_mean_scalars() {
+        let degrees = [350.0, 10.0, 5.0];
+        let typed: Deg<f64> = mean(degrees.iter().map(|&d| Deg(d)));
+        assert_eq!(circular_mean_degrees(&degrees), typed.0);
+        assert_relative_eq!(circular_mean_degrees(&[350.0, 10.0]), 0.0, epsilon = 1e-9);
+        assert_relative_eq!(circular_mean_degrees(&[80.0, 100.0]), 90.0, epsilon = 1e-9);
+
+        let radians = [0.1, 2.0 * consts::PI - 0.1];
+        assert_relative_eq!(WrapApprox(Rad(circular_mean_radians(&radians))), WrapApprox(Rad(0.0)),
+                            epsilon = 1e-9);
+        assert_relative_eq!(circular_mean_radians(&[1.0, 2.0]), 1.5, epsilon = 1e-9);
+        assert_eq!(circular_mean_degrees(&[]), 0.0);
     }
 
     #[test]
-    fn test_arithmetic() {
-        {
-            let a1 = Rad(2.0);
-            let a2 = Deg(100.0);
+    fn test_rotate_sincos() {
+        let (sin, cos) = Deg::rotate_sincos(0.0, 1.0, Deg(90.0));
+        assert_relative_eq!(sin, 1.0);
+        assert_relative_eq!(cos, 0.0, epsilon = 1e-15);
 
-            let a3 = a2 + a1;
-            assert_relative_eq!(a3.0, 214.59, epsilon=1e-2);
+        let start = Deg(25.0);
+        let (mut sin, mut cos) = start.sin_cos();
+        for step in 1..=36 {
+            let (next_sin, next_cos) = Deg::rotate_sincos(sin, cos, Deg(10.0));
+            sin = next_sin;
+            cos = next_cos;
+            let direct = Deg(25.0 + 10.0 * step as f64);
+            assert_relative_eq!(sin, direct.sin(), epsilon = 1e-12);
+            assert_relative_eq!(cos, direct.cos(), epsilon = 1e-12);
+        }
+        assert_relative_eq!(sin, start.sin(), epsilon = 1e-12);
+        assert_relative_eq!(cos, start.cos(), epsilon = 1e-12);
+    }
 
-            let a4 = Deg(50.0);
-            let a5 = a2 + a4;
-            assert_ulps_eq!(a5.0, 150.0);
+    #[test]
+    fn test_progressing_toward() {
+        assert!(Deg(30.0).progressing_toward(Deg(10.0), Deg(50.0)));
+        assert!(Deg(50.0).progressing_toward(Deg(10.0), Deg(50.0)));
+        assert!(!Deg(10.0).progressing_toward(Deg(10.0), Deg(50.0)));
+        assert!(!Deg(60.0).progressing_toward(Deg(10.0), Deg(50.0)));
+        assert!(!Deg(0.0).progressing_toward(Deg(10.0), Deg(50.0)));
 
-            let mut a6 = Deg(123.0);
-            a6 += Deg(10.0);
-            a6 += Rad::pi();
-            assert_ulps_eq!(a6.0, 313.0);
+        assert!(Deg(5.0).progressing_toward(Deg(340.0), Deg(20.0)));
+        assert!(Deg(350.0).progressing_toward(Deg(340.0), Deg(20.0)));
+        assert!(!Deg(30.0).progressing_toward(Deg(340.0), Deg(20.0)));
+        assert!(!Deg(330.0).progressing_toward(Deg(340.0), Deg(20.0)));
+        assert!(Deg(355.0).progressing_toward(Deg(20.0), Deg(340.0)));
+        assert!(!Deg(25.0).progressing_toward(Deg(20.0), Deg(340.0)));
+        assert!(!Deg(20.0).progressing_toward(Deg(20.0), Deg(20.0)));
+    }
 
-            let a7 = Deg(50.0);
-            assert_ulps_eq!(a7 * 2.0, Deg(100.0));
-        }
-        {
-            let a1 = Rad(2.0);
-            let a2 = a1 % Rad(1.5);
-            assert_ulps_eq!(a2, Rad(0.5));
-            assert_ulps_eq!(Rad(1.0) * 2.0, Rad(2.0));
-            assert_ulps_eq!(Rad(consts::PI * 2.0) / 2.0, Rad(consts::PI));
-        }
-        {
-            let a10 = Gon(15.0);
-            let a11 = Deg(43.0);
-            let a12 = a11 + a10;
-            assert_relative_eq!(a12.0, 56.5, epsilon=1e-2);
-            let a13 = a10 + a11;
-            assert_relative_eq!(a13.0, 62.7778, epsilon=1e-2);
+    #[test]
+    fn test_dyadic_turns_round_trip_exact() {
+        for &turns in &[0.25, 0.5, 0.125, 0.0625, 0.75] {
+            let deg: Deg<f64> = Turns(turns).into_angle();
+            let rad: Rad<f64> = Turns(turns).into_angle();
+            assert_eq!(deg, Deg(turns * 360.0));
+            assert_eq!(Turns::from_angle(deg), Turns(turns));
+            assert_eq!(Turns::from_angle(rad), Turns(turns));
+            assert_eq!(Rad::from_angle(deg), rad);
+            assert_eq!(Deg::from_angle(rad), deg);
+            assert_eq!(Turns::from_angle(Rad::from_angle(deg)), Turns(turns));
         }
+        assert_eq!(Turns::from_angle(Deg::from_angle(Turns(0.25f32))), Turns(0.25f32));
     }
 
     #[test]
-    fn test_trig() {
-        assert_ulps_eq!(Deg(0.0).sin(), 0.0);
-        assert_ulps_eq!(Gon(0.0).sin(), 0.0);
-        assert_ulps_eq!(Rad(consts::PI / 2.0).sin(), 1.0);
-        assert_ulps_eq!(Deg(90.0).sin(), 1.0);
-        assert_ulps_eq!(Deg(45.0).tan(), 1.0);
-        assert_relative_eq!(Deg(405.0).tan(), 1.0, epsilon=1e-6);
-        assert_relative_eq!(Gon(450.0).tan(), 1.0, epsilon=1e-6);
-        let a1 = Rad(consts::PI * 1.25);
-        assert_relative_eq!(a1.cos(), -f64::sqrt(2.0) / 2.0, epsilon=1e-6);
-        assert_relative_eq!(a1.cos(), Deg(135.0).cos(), epsilon=1e-6);
-        assert_relative_eq!(a1.cos(), Gon(150.0).cos(), epsilon=1e-6);
+    fn test_angle_builder() {
+        let deg = AngleBuilder::new().degrees(50.0).arcminutes(30.0).arcseconds(36.0)
+            .build::<Deg<f64>>();
+        assert_eq!(deg, Deg::from_components(Deg(50.0), ArcMinutes(30.0), ArcSeconds(36.0)));
+        assert_relative_eq!(deg, Deg(50.51));
 
-        assert_relative_eq!(Deg::acos(1.0), Deg(0.0));
-        assert_relative_eq!(Deg::acos(0.0), Deg(90.0));
-        assert_relative_eq!(Deg::acos(0.0), Deg::from(Gon(100.0)));
-        assert_relative_eq!(Rad::acos(0.0), Rad::pi_over_2());
+        let rad = AngleBuilder::new().degrees(90.0).radians(consts::PI).build::<Rad<f64>>();
+        assert_relative_eq!(rad, Rad(1.5 * consts::PI));
+        let turns = AngleBuilder::new().turns(0.5).gons(100.0).angle(Rad(consts::PI / 2.0))
+            .build::<Turns<f64>>();
+        assert_relative_eq!(turns, Turns(1.0));
+        assert_eq!(AngleBuilder::<f64>::new().build::<Gon<f64>>(), Gon(0.0));
     }
 
     #[test]
-    fn test_equality() {
-        let a1 = Rad(2.0);
-        assert_ulps_eq!(a1, Rad(2.0));
-        assert_ulps_eq!(Deg(200.0), Deg(200.0));
-        assert!(!(Deg(200.0) == Deg(100.0)));
+    fn test_clamp_symmetric() {
+        let limit = Deg(120.0);
+        assert_relative_eq!(Deg(30.0).clamp_symmetric(limit), Deg(30.0));
+        assert_relative_eq!(Deg(-90.0).clamp_symmetric(limit), Deg(-90.0));
+        assert_relative_eq!(Deg(330.0).clamp_symmetric(limit), Deg(-30.0));
+        assert_relative_eq!(Deg(150.0).clamp_symmetric(limit), Deg(120.0));
+        assert_relative_eq!(Deg(-150.0).clamp_symmetric(limit), Deg(-120.0));
+        assert_relative_eq!(Deg(200.0).clamp_symmetric(limit), Deg(-120.0));
+        assert_relative_eq!(Deg(120.0).clamp_symmetric(limit), Deg(120.0));
+        assert_relative_eq!(Deg(-120.0).clamp_symmetric(limit), Deg(-120.0));
+        assert!(Deg(f64::NAN).clamp_symmetric(limit).scalar().is_nan());
+        assert!(Deg(30.0).clamp_symmetric(Deg(f64::NAN)).scalar().is_nan());
+    }
 
-        assert!(Deg(200.0) < Deg(300.0));
-        assert!(Deg(250.0) > Deg(100.0));
+    #[test]
+    fn test_value_and_unit() {
+        assert_eq!(Deg(90.0).value_and_unit(), (90.0, "°"));
+        assert_eq!(Rad(1.5).value_and_unit(), (1.5, "r"));
+        assert_eq!(Gon(50.0).value_and_unit(), (50.0, "gon"));
+        assert_eq!(Turns(0.25).value_and_unit(), (0.25, ""));
+        assert_eq!(ArcMinutes(30.0).value_and_unit(), (30.0, "'"));
+        assert_eq!(ArcSeconds(15.0).value_and_unit(), (15.0, "\""));
+        assert_eq!(Revolutions(2.0).value_and_unit(), (2.0, "rev"));
 
-        assert_relative_eq!(Deg(359.999999), Deg(0.0), epsilon=1e-4);
-        assert_ulps_eq!(Deg(359.999999), Deg(0.0), epsilon=1e-4);
-        assert_ulps_eq!(Deg(359.99999), Deg(0.0), epsilon=1e-4);
+        let (value, unit) = Gon(12.5).value_and_unit();
+        assert_eq!(format!("{}{}", value, unit), format!("{}", Gon(12.5)));
+        let (value, unit) = ArcSeconds(7.5).value_and_unit();
+        assert_eq!(format!("{}{}", value, unit), format!("{}", ArcSeconds(7.5)));
     }
 
     #[test]
-    fn test_normalize() {
-        let mut a1 = Deg(200.0);
-        a1 += Deg(300.0);
-        assert_ulps_eq!(a1, Deg(500.0));
-        a1 = a1.normalize();
-        assert_ulps_eq!(a1, Deg(140.0));
-        assert_ulps_eq!(a1.normalize(), a1);
+    fn test_sign_changes() {
+        let errors = [Deg(20.0), Deg(5.0), Deg(355.0), Deg(350.0), Deg(2.0), Deg(1.0)];
+        assert_eq!(sign_changes(&errors), vec![1, 3]);
+        assert_eq!(sign_changes(&[Deg(5.0), Deg(0.0), Deg(-5.0)]), Vec::<usize>::new());
+        assert!(sign_changes(&[Deg(5.0)]).is_empty());
+        assert!(sign_changes::<Deg<f64>>(&[]).is_empty());
+    }
 
-        let a2 = Deg(50.0);
-        assert_ulps_eq!(a2 - Deg(150.0), Deg(-100.0));
-        let a3 = a2 - Deg(100.0);
-        assert!(!a3.is_normalized());
-        assert_ulps_eq!(a3.normalize(), Deg(310.0));
-        assert_ulps_eq!(a3.normalize().normalize(), a3.normalize());
-        assert!(a3.normalize().is_normalized());
+    #[test]
+    fn test_orthogonal() {
+        assert_relative_eq!(Deg(30.0).orthogonal_ccw(), Deg(120.0));
+        assert_relative_eq!(Deg(30.0).orthogonal_cw(), Deg(300.0));
+        assert_relative_eq!(Deg(300.0).orthogonal_ccw(), Deg(30.0));
+        assert_relative_eq!(Deg(90.0).orthogonal_cw(), Deg(0.0));
+        assert_relative_eq!(Deg(-30.0).orthogonal_ccw(), Deg(60.0));
+        assert_relative_eq!(Deg(30.0).antipode(), Deg(210.0));
+        assert_relative_eq!(Deg(270.0).antipode(), Deg(90.0));
+        assert_relative_eq!(Deg(-30.0).antipode(), Deg(150.0));
+        assert_relative_eq!(Deg(30.0).orthogonal_ccw().orthogonal_ccw(), Deg(30.0).antipode());
+    }
 
-        let a4 = Rad(consts::PI);
-        let a5 = a4 + Rad(consts::PI * 2.0);
-        assert_ulps_eq!(a5, Rad(consts::PI * 3.0));
-        assert!(!a3.is_normalized());
-        assert_ulps_eq!(a5.normalize(), Rad(consts::PI));
-        let a6 = a4 - Rad(consts::PI * 2.0);
-        assert_ulps_eq!(a6, Rad(consts::PI * -1.0));
-        assert!(!a6.is_normalized());
-        assert_ulps_eq!(a6.normalize(), a5.normalize());
+    #[test]
+    fn test_angle_histogram() {
+        let angles = [
+            Deg(0.0),
+            Deg(10.0),
+            Deg(44.9),
+            Deg(45.0),
+            Deg(100.0),
+            Deg(180.0),
+            Deg(200.0),
+            Deg(315.0),
+            Deg(359.9),
+            Deg(360.0),
+            Deg(-10.0),
+        ];
+        assert_eq!(angle_histogram(&angles, 8), vec![4, 1, 1, 0, 2, 0, 0, 3]);
+        assert_eq!(angle_histogram::<Deg<f64>>(&[], 4), vec![0, 0, 0, 0]);
+        assert!(angle_histogram(&angles, 0).is_empty());
 
-        assert_ulps_eq!(Deg(360.0).normalize(), Deg(0.0));
-        assert_ulps_eq!(Deg(-1.0).normalize(), Deg(359.0));
-        assert_ulps_eq!(Deg(-360.0).normalize(), Deg(0.0));
-        assert_relative_eq!(Deg(-359.9).normalize(), Deg(0.1), epsilon=1e-6);
+        let noisy = [Deg(10.0), Deg(f64::NAN), Deg(100.0), Deg(f64::INFINITY), Deg(f64::NEG_INFINITY)];
+        assert_eq!(angle_histogram(&noisy, 4), vec![1, 1, 0, 0]);
+    }
 
-        assert_relative_eq!(Gon(725.0).normalize().into_angle(), Deg(292.5), epsilon=1e-6);
-        assert_relative_eq!(Gon(-275.0).normalize(), Gon(125.0), epsilon=1e-6);
+    #[test]
+    fn test_interpolate_via() {
+        let start = ArcSeconds(1_295_000.0);
+        let end = ArcSeconds(1_000.0);
+        let direct = start.interpolate(&end, 0.5);
+        let via = start.interpolate_via::<Turns<f64>, _>(&end, 0.5);
+        assert_relative_eq!(via, direct, epsilon = 1e-6);
+        assert_relative_eq!(via.wrapped_signed(), ArcSeconds(0.0), epsilon = 1e-6);
+
+        let start = ArcSeconds(1_295_000.0_f32);
+        let end = ArcSeconds(1_000.0_f32);
+        let via = start.interpolate_via::<Turns<f32>, _>(&end, 0.25);
+        assert_relative_eq!(via.normalize(), ArcSeconds(1_295_500.0), epsilon = 0.5);
+        assert_relative_eq!(Deg(350.0).interpolate_via::<Rad<f64>, _>(&Deg(30.0), 0.5).normalize(),
+                            Deg(10.0),
+                            epsilon = 1e-9);
     }
 
     #[test]
-    fn decompose() {
-        {
-            let (deg, min, sec) = Deg(50.25).decompose();
+    fn test_is_between() {
+        assert!(Deg(10.0).is_between_inclusive(Deg(10.0), Deg(80.0)));
+        assert!(!Deg(10.0).is_between_exclusive(Deg(10.0), Deg(80.0)));
+        assert!(Deg(80.0).is_between_inclusive(Deg(10.0), Deg(80.0)));
+        assert!(!Deg(80.0).is_between_exclusive(Deg(10.0), Deg(80.0)));
+        assert!(Deg(45.0).is_between_exclusive(Deg(10.0), Deg(80.0)));
+        assert!(!Deg(90.0).is_between_inclusive(Deg(10.0), Deg(80.0)));
 
-            assert_ulps_eq!(deg, Deg(50.0));
-            assert_ulps_eq!(min, ArcMinutes(15.0));
-            assert_ulps_eq!(sec, ArcSeconds(0.0));
-        }
-        {
-            let (deg, min, sec) = Deg(90.3131).decompose();
+        assert!(Deg(350.0).is_between_inclusive(Deg(350.0), Deg(20.0)));
+        assert!(!Deg(350.0).is_between_exclusive(Deg(350.0), Deg(20.0)));
+        assert!(Deg(380.0).is_between_inclusive(Deg(350.0), Deg(20.0)));
+        assert!(!Deg(20.0).is_between_exclusive(Deg(350.0), Deg(20.0)));
+        assert!(Deg(0.0).is_between_exclusive(Deg(350.0), Rad(0.2)));
+        assert!(!Deg(180.0).is_between_inclusive(Deg(350.0), Deg(20.0)));
 
-            assert_ulps_eq!(deg, Deg(90.0));
-            assert_ulps_eq!(min, ArcMinutes(18.0));
-            assert_relative_eq!(sec, ArcSeconds(47.16), epsilon=1e-6);
-        }
+        assert!(Deg(30.0).is_between_inclusive(Deg(30.0), Deg(390.0)));
+        assert!(!Deg(30.0).is_between_exclusive(Deg(30.0), Deg(30.0)));
     }
 
     #[test]
-    fn test_interpolate() {
-        assert_relative_eq!(Deg(60.0).interpolate(&Deg(120.0), 0.5), Deg(90.0));
-        assert_relative_eq!(Deg(50.0).interpolate(&Rad(consts::PI), 0.75), 
-                            Deg(147.5), epsilon=1e-6);
-        assert_relative_eq!(Turns(0.50).interpolate(&Deg(30.0), 0.25), 
-                            Turns(0.39583333333), epsilon=1e-6);
+    fn test_snap_to_set() {
+        let detents = [Deg(0.0), Deg(50.0), Deg(200.0)];
+        assert_eq!(Deg(20.0).snap_to_set(detents.iter().cloned()), Some(Deg(0.0)));
+        assert_eq!(Deg(30.0).snap_to_set(detents.iter().cloned()), Some(Deg(50.0)));
+        assert_eq!(Deg(130.0).snap_to_set(detents.iter().cloned()), Some(Deg(200.0)));
+        assert_eq!(Deg(290.0).snap_to_set(detents.iter().cloned()), Some(Deg(0.0)));
+        assert_eq!(Deg(-10.0).snap_to_set(detents.iter().cloned()), Some(Deg(0.0)));
+        assert_eq!(Deg(25.0).snap_to_set(detents.iter().cloned()), Some(Deg(0.0)));
+        assert_eq!(Deg(25.0).snap_to_set(Vec::new()), None);
+    }
 
-        assert_relative_eq!(Deg(100.0).interpolate(&Deg(310.0), 0.5).normalize(), Deg(25.0));
-        assert_relative_eq!(Deg(100.0).interpolate_forward(&Deg(310.0), 0.5).normalize(), 
-                            Deg(205.0));
-        assert_relative_eq!(Gon(66.6666667).interpolate(&Deg(120.0), 0.5), Gon(100.0), epsilon=1e-6);
-        assert_relative_eq!(Rad::pi_over_2().interpolate(&Rad(0.0), 0.5), Rad::pi_over_4());
+    #[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+    struct Hours(f64);
+
+    impl Add for Hours {
+        type Output = Hours;
+        fn add(self, rhs: Hours) -> Hours {
+            Hours(self.0 + rhs.0)
+        }
     }
 
-    #[test]
-    fn test_constants() {
-        assert_ulps_eq!(Deg::half_turn(), Deg(180.0));
-        assert_ulps_eq!(Deg::quarter_turn(), Deg(90.0));
-        assert_ulps_eq!(Rad::half_turn(), Rad(consts::PI));
-        assert_ulps_eq!(Rad::<f32>::full_turn(), Rad(Rad::period()));
-        assert_ulps_eq!(Gon::half_turn(), Gon(200.0));
-        assert_ulps_eq!(Gon::quarter_turn(), Gon(100.0));
+    impl num::Zero for Hours {
+        fn zero() -> Hours {
+            Hours(0.0)
+        }
+        fn is_zero(&self) -> bool {
+            self.0 == 0.0
+        }
     }
 
-    #[test]
-    fn test_invert() {
-        assert_ulps_eq!(Deg(0.0).invert(), Deg(180.0));
-        assert_ulps_eq!(Deg(180.0).invert().normalize(), Deg(0.0));
-        assert_ulps_eq!(Gon(200.0).invert().normalize(), Gon(0.0));
-        assert_ulps_eq!(Deg(80.0).invert(), Deg(260.0));
-        assert_ulps_eq!(Gon(80.0).invert(), Gon(280.0));
+    impl<U: Angle<Scalar = f64>> FromAngle<U> for Hours {
+        fn from_angle(from: U) -> Hours {
+            Hours(from.scalar() / U::period() * 24.0)
+        }
+    }
+
+    impl Angle for Hours {
+        type Scalar = f64;
+        fn new(value: f64) -> Hours {
+            Hours(value)
+        }
+        fn period() -> f64 {
+            24.0
+        }
+        fn scalar(&self) -> f64 {
+            self.0
+        }
+        fn set_scalar(&mut self, value: f64) {
+            self.0 = value;
+        }
+        fn normalize(self) -> Hours {
+            Hours(self.0.rem_euclid(24.0))
+        }
+        fn is_normalized(&self) -> bool {
+            self.0 >= 0.0 && self.0 < 24.0
+        }
+        fn sin(self) -> f64 {
+            Rad::from_angle(self).0.sin()
+        }
+        fn cos(self) -> f64 {
+            Rad::from_angle(self).0.cos()
+        }
+        fn tan(self) -> f64 {
+            Rad::from_angle(self).0.tan()
+        }
+        fn sin_cos(self) -> (f64, f64) {
+            Rad::from_angle(self).0.sin_cos()
+        }
+        fn asin(value: f64) -> Hours {
+            Hours::from_radians(value.asin())
+        }
+        fn acos(value: f64) -> Hours {
+            Hours::from_radians(value.acos())
+        }
+        fn atan(value: f64) -> Hours {
+            Hours::from_radians(value.atan())
+        }
+        fn atan2(y: f64, x: f64) -> Hours {
+            Hours::from_radians(y.atan2(x))
+        }
+        fn full_turn() -> Hours {
+            Hours(24.0)
+        }
+        fn half_turn() -> Hours {
+            Hours(12.0)
+        }
+        fn quarter_turn() -> Hours {
+            Hours(6.0)
+        }
+        fn invert(self) -> Hours {
+            Hours(self.0 + 12.0)
+        }
+        fn reflect_x(self) -> Hours {
+            Hours(24.0 - self.0)
+        }
     }
 
     #[test]
-    fn test_reflect_x() {
-        assert_relative_eq!(Deg(359.9999999999).reflect_x(), 
-            Deg(0.0000000000001), epsilon=1e-5);
-        assert_relative_eq!(Deg(180.0).reflect_x(), Deg(180.0));
-        assert_relative_eq!(Deg(90.0).reflect_x(), Deg(90.0));
-        assert_relative_eq!(Deg(0.0).reflect_x(), Deg(0.0));
-        assert_relative_eq!(Deg(45.0).reflect_x(), Deg(315.0));
-        assert_relative_eq!(Deg(215.0).reflect_x(), Deg(145.0));
-        assert_relative_eq!(Gon(50.0).reflect_x(), Gon(350.0));
-        assert_relative_eq!(Gon(215.0).reflect_x(), Gon(185.0));
+    fn test_external_angle_type() {
+        assert_eq!(Hours(30.0).wrapped_signed(), Hours(6.0));
+        assert_eq!(Hours(1.0).delta(&Hours(23.0)), Hours(2.0));
+        assert_eq!(Hours(6.0).as_deg(), Deg(90.0));
+        assert_eq!(Hours::from_degrees(180.0), Hours(12.0));
+        assert_eq!(Hours(20.0).back_bearing(), Hours(8.0));
+        assert_relative_eq!(Hours(6.0).sin_cos_tan().0, 1.0);
     }
 
     #[test]
-    fn test_mean() {
-        assert_relative_eq!(mean(vec![Deg(280.0), Deg(10.0)].into_iter()), Deg(325.0));
-        assert_relative_eq!(mean(vec![Turns(0.5), Turns(0.0)].into_iter()), Deg(90.0));
-        assert_relative_eq!(mean([Rad(0.0), Rad(0.0)].into_iter().cloned()), Rad(0.0));
+    fn test_display_width() {
+        assert_eq!(format!("{:>8}|", Deg(5.0)), "      5°|");
+        assert_eq!(format!("{:8}|", Deg(5.0)), "      5°|");
+        assert_eq!(format!("{:<8}|", Deg(5.0)), "5°      |");
+        assert_eq!(format!("{:^8}|", Gon(5.0)), "  5gon  |");
+        assert_eq!(format!("{:*>8.1}|", Rad(2.0)), "****2.0r|");
+        assert_eq!(format!("{:>5}|", Turns(0.5)), "  0.5|");
+        assert_eq!(format!("{:+}", ArcMinutes(3.0)), "+3'");
+        assert_eq!(format!("{:2}", Deg(180.0)), "180°");
     }
 
     #[cfg(feature = "serde")]